@@ -0,0 +1,17 @@
+import { Address, getAddressCodec } from "gill";
+
+const addressCodec = getAddressCodec()
+
+// same as `dephy_rewards::claim_for_message`, signed by the owner for `claim_rewards_for`
+export function getClaimForMessage(rewardsState: Address, totalRewards: bigint, beneficiary: Address, nonce: bigint): Buffer {
+  const totalRewardsBuffer = Buffer.alloc(8);
+  totalRewardsBuffer.writeBigUInt64LE(totalRewards, 0);
+  const nonceBuffer = Buffer.alloc(8);
+  nonceBuffer.writeBigUInt64LE(nonce, 0);
+  return Buffer.concat([
+    Buffer.from(addressCodec.encode(rewardsState)),
+    totalRewardsBuffer,
+    Buffer.from(addressCodec.encode(beneficiary)),
+    nonceBuffer,
+  ]);
+}
@@ -48,12 +48,24 @@ export function getClaimStateDiscriminatorBytes() {
 export type ClaimState = {
   discriminator: ReadonlyUint8Array;
   owner: Address;
+  /** cumulative, root totals only grow */
   claimedRewards: bigint;
+  lastClaimedVersion: bigint;
+  /** next nonce of a relayed claim */
+  nonce: bigint;
+  /** claim states of the primary mint from before extra mints get it on their next claim */
+  mint: Address;
 };
 
 export type ClaimStateArgs = {
   owner: Address;
+  /** cumulative, root totals only grow */
   claimedRewards: number | bigint;
+  lastClaimedVersion: number | bigint;
+  /** next nonce of a relayed claim */
+  nonce: number | bigint;
+  /** claim states of the primary mint from before extra mints get it on their next claim */
+  mint: Address;
 };
 
 export function getClaimStateEncoder(): Encoder<ClaimStateArgs> {
@@ -62,6 +74,9 @@ export function getClaimStateEncoder(): Encoder<ClaimStateArgs> {
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['owner', getAddressEncoder()],
       ['claimedRewards', getU64Encoder()],
+      ['lastClaimedVersion', getU64Encoder()],
+      ['nonce', getU64Encoder()],
+      ['mint', getAddressEncoder()],
     ]),
     (value) => ({ ...value, discriminator: CLAIM_STATE_DISCRIMINATOR })
   );
@@ -72,6 +87,9 @@ export function getClaimStateDecoder(): Decoder<ClaimState> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['owner', getAddressDecoder()],
     ['claimedRewards', getU64Decoder()],
+    ['lastClaimedVersion', getU64Decoder()],
+    ['nonce', getU64Decoder()],
+    ['mint', getAddressDecoder()],
   ]);
 }
 
@@ -133,5 +151,5 @@ export async function fetchAllMaybeClaimState(
 }
 
 export function getClaimStateSize(): number {
-  return 48;
+  return 96;
 }
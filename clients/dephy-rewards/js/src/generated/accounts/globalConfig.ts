@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   transformEncoder,
@@ -32,6 +34,8 @@ import {
   type FetchAccountsConfig,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 
@@ -48,15 +52,20 @@ export function getGlobalConfigDiscriminatorBytes() {
 export type GlobalConfig = {
   discriminator: ReadonlyUint8Array;
   admin: Address;
+  pendingAdmin: Option<Address>;
 };
 
-export type GlobalConfigArgs = { admin: Address };
+export type GlobalConfigArgs = {
+  admin: Address;
+  pendingAdmin: OptionOrNullable<Address>;
+};
 
 export function getGlobalConfigEncoder(): Encoder<GlobalConfigArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['admin', getAddressEncoder()],
+      ['pendingAdmin', getOptionEncoder(getAddressEncoder())],
     ]),
     (value) => ({ ...value, discriminator: GLOBAL_CONFIG_DISCRIMINATOR })
   );
@@ -66,6 +75,7 @@ export function getGlobalConfigDecoder(): Decoder<GlobalConfig> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['admin', getAddressDecoder()],
+    ['pendingAdmin', getOptionDecoder(getAddressDecoder())],
   ]);
 }
 
@@ -125,7 +135,3 @@ export async function fetchAllMaybeGlobalConfig(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeGlobalConfig(maybeAccount));
 }
-
-export function getGlobalConfigSize(): number {
-  return 40;
-}
@@ -8,4 +8,5 @@
 
 export * from './claimState';
 export * from './globalConfig';
+export * from './merkleRootVersion';
 export * from './rewardsState';
@@ -17,10 +17,14 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -57,6 +61,10 @@ export type RewardsState = {
   rewardsMint: Address;
   rewardsTokenAccount: Address;
   merkleRoot: MerkleRoot;
+  /** version of `merkle_root`, zero before the first one */
+  version: bigint;
+  /** paid from the rewards vault's associated token accounts, leaves commit to the mint */
+  extraRewardsMints: Array<Address>;
 };
 
 export type RewardsStateArgs = {
@@ -64,6 +72,10 @@ export type RewardsStateArgs = {
   rewardsMint: Address;
   rewardsTokenAccount: Address;
   merkleRoot: MerkleRootArgs;
+  /** version of `merkle_root`, zero before the first one */
+  version: number | bigint;
+  /** paid from the rewards vault's associated token accounts, leaves commit to the mint */
+  extraRewardsMints: Array<Address>;
 };
 
 export function getRewardsStateEncoder(): Encoder<RewardsStateArgs> {
@@ -74,6 +86,8 @@ export function getRewardsStateEncoder(): Encoder<RewardsStateArgs> {
       ['rewardsMint', getAddressEncoder()],
       ['rewardsTokenAccount', getAddressEncoder()],
       ['merkleRoot', getMerkleRootEncoder()],
+      ['version', getU64Encoder()],
+      ['extraRewardsMints', getArrayEncoder(getAddressEncoder())],
     ]),
     (value) => ({ ...value, discriminator: REWARDS_STATE_DISCRIMINATOR })
   );
@@ -86,6 +100,8 @@ export function getRewardsStateDecoder(): Decoder<RewardsState> {
     ['rewardsMint', getAddressDecoder()],
     ['rewardsTokenAccount', getAddressDecoder()],
     ['merkleRoot', getMerkleRootDecoder()],
+    ['version', getU64Decoder()],
+    ['extraRewardsMints', getArrayDecoder(getAddressDecoder())],
   ]);
 }
 
@@ -20,18 +20,54 @@ export const DEPHY_REWARDS_ERROR__INVALID_AUTHORITY = 0x1770; // 6000
 export const DEPHY_REWARDS_ERROR__INVALID_PROOF = 0x1771; // 6001
 /** AlreadyClaimed: Already claimed */
 export const DEPHY_REWARDS_ERROR__ALREADY_CLAIMED = 0x1772; // 6002
+/** InvalidMerkleRootAccount: Invalid merkle root account */
+export const DEPHY_REWARDS_ERROR__INVALID_MERKLE_ROOT_ACCOUNT = 0x1773; // 6003
+/** ConsensusNotReached: Consensus not reached */
+export const DEPHY_REWARDS_ERROR__CONSENSUS_NOT_REACHED = 0x1774; // 6004
+/** RootVersionExpired: Merkle root version no longer claimable */
+export const DEPHY_REWARDS_ERROR__ROOT_VERSION_EXPIRED = 0x1775; // 6005
+/** InvalidSignature: Invalid owner signature */
+export const DEPHY_REWARDS_ERROR__INVALID_SIGNATURE = 0x1776; // 6006
+/** InvalidNonce: Invalid nonce */
+export const DEPHY_REWARDS_ERROR__INVALID_NONCE = 0x1777; // 6007
+/** NothingVested: Nothing vested yet */
+export const DEPHY_REWARDS_ERROR__NOTHING_VESTED = 0x1778; // 6008
+/** InvalidRewardsMint: Invalid rewards mint */
+export const DEPHY_REWARDS_ERROR__INVALID_REWARDS_MINT = 0x1779; // 6009
+/** TooManyRewardsMints: Too many rewards mints */
+export const DEPHY_REWARDS_ERROR__TOO_MANY_REWARDS_MINTS = 0x177a; // 6010
+/** AlreadyMigrated: Account already migrated */
+export const DEPHY_REWARDS_ERROR__ALREADY_MIGRATED = 0x177b; // 6011
 
 export type DephyRewardsError =
   | typeof DEPHY_REWARDS_ERROR__ALREADY_CLAIMED
+  | typeof DEPHY_REWARDS_ERROR__ALREADY_MIGRATED
+  | typeof DEPHY_REWARDS_ERROR__CONSENSUS_NOT_REACHED
   | typeof DEPHY_REWARDS_ERROR__INVALID_AUTHORITY
-  | typeof DEPHY_REWARDS_ERROR__INVALID_PROOF;
+  | typeof DEPHY_REWARDS_ERROR__INVALID_MERKLE_ROOT_ACCOUNT
+  | typeof DEPHY_REWARDS_ERROR__INVALID_NONCE
+  | typeof DEPHY_REWARDS_ERROR__INVALID_PROOF
+  | typeof DEPHY_REWARDS_ERROR__INVALID_REWARDS_MINT
+  | typeof DEPHY_REWARDS_ERROR__INVALID_SIGNATURE
+  | typeof DEPHY_REWARDS_ERROR__NOTHING_VESTED
+  | typeof DEPHY_REWARDS_ERROR__ROOT_VERSION_EXPIRED
+  | typeof DEPHY_REWARDS_ERROR__TOO_MANY_REWARDS_MINTS;
 
 let dephyRewardsErrorMessages: Record<DephyRewardsError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   dephyRewardsErrorMessages = {
     [DEPHY_REWARDS_ERROR__ALREADY_CLAIMED]: `Already claimed`,
+    [DEPHY_REWARDS_ERROR__ALREADY_MIGRATED]: `Account already migrated`,
+    [DEPHY_REWARDS_ERROR__CONSENSUS_NOT_REACHED]: `Consensus not reached`,
     [DEPHY_REWARDS_ERROR__INVALID_AUTHORITY]: `Invalid authority`,
+    [DEPHY_REWARDS_ERROR__INVALID_MERKLE_ROOT_ACCOUNT]: `Invalid merkle root account`,
+    [DEPHY_REWARDS_ERROR__INVALID_NONCE]: `Invalid nonce`,
     [DEPHY_REWARDS_ERROR__INVALID_PROOF]: `Invalid proof`,
+    [DEPHY_REWARDS_ERROR__INVALID_REWARDS_MINT]: `Invalid rewards mint`,
+    [DEPHY_REWARDS_ERROR__INVALID_SIGNATURE]: `Invalid owner signature`,
+    [DEPHY_REWARDS_ERROR__NOTHING_VESTED]: `Nothing vested yet`,
+    [DEPHY_REWARDS_ERROR__ROOT_VERSION_EXPIRED]: `Merkle root version no longer claimable`,
+    [DEPHY_REWARDS_ERROR__TOO_MANY_REWARDS_MINTS]: `Too many rewards mints`,
   };
 }
 
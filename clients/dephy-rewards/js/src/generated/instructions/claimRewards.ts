@@ -11,17 +11,11 @@ import {
   fixDecoderSize,
   fixEncoderSize,
   getAddressEncoder,
-  getArrayDecoder,
-  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
-  getU32Decoder,
-  getU32Encoder,
-  getU64Decoder,
-  getU64Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -45,6 +39,12 @@ import {
   getAccountMetaFactory,
   type ResolvedAccount,
 } from '../shared';
+import {
+  getClaimRewardsArgsDecoder,
+  getClaimRewardsArgsEncoder,
+  type ClaimRewardsArgs,
+  type ClaimRewardsArgsArgs,
+} from '../types';
 
 export const CLAIM_REWARDS_DISCRIMINATOR = new Uint8Array([
   4, 144, 132, 71, 116, 23, 151, 80,
@@ -68,6 +68,7 @@ export type ClaimRewardsInstruction<
     | string
     | IAccountMeta<string> = string,
   TAccountMaybeMerkleRootAccount extends string | IAccountMeta<string> = string,
+  TAccountMerkleRootVersion extends string | IAccountMeta<string> = string,
   TAccountPayer extends string | IAccountMeta<string> = string,
   TAccountRewardsTokenProgram extends string | IAccountMeta<string> = string,
   TAccountSystemProgram extends
@@ -103,6 +104,9 @@ export type ClaimRewardsInstruction<
       TAccountMaybeMerkleRootAccount extends string
         ? ReadonlyAccount<TAccountMaybeMerkleRootAccount>
         : TAccountMaybeMerkleRootAccount,
+      TAccountMerkleRootVersion extends string
+        ? ReadonlyAccount<TAccountMerkleRootVersion>
+        : TAccountMerkleRootVersion,
       TAccountPayer extends string
         ? WritableSignerAccount<TAccountPayer> &
             IAccountSignerMeta<TAccountPayer>
@@ -119,24 +123,16 @@ export type ClaimRewardsInstruction<
 
 export type ClaimRewardsInstructionData = {
   discriminator: ReadonlyUint8Array;
-  index: number;
-  totalRewards: bigint;
-  proof: Array<ReadonlyUint8Array>;
+  args: ClaimRewardsArgs;
 };
 
-export type ClaimRewardsInstructionDataArgs = {
-  index: number;
-  totalRewards: number | bigint;
-  proof: Array<ReadonlyUint8Array>;
-};
+export type ClaimRewardsInstructionDataArgs = { args: ClaimRewardsArgsArgs };
 
 export function getClaimRewardsInstructionDataEncoder(): Encoder<ClaimRewardsInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
-      ['index', getU32Encoder()],
-      ['totalRewards', getU64Encoder()],
-      ['proof', getArrayEncoder(fixEncoderSize(getBytesEncoder(), 32))],
+      ['args', getClaimRewardsArgsEncoder()],
     ]),
     (value) => ({ ...value, discriminator: CLAIM_REWARDS_DISCRIMINATOR })
   );
@@ -145,9 +141,7 @@ export function getClaimRewardsInstructionDataEncoder(): Encoder<ClaimRewardsIns
 export function getClaimRewardsInstructionDataDecoder(): Decoder<ClaimRewardsInstructionData> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
-    ['index', getU32Decoder()],
-    ['totalRewards', getU64Decoder()],
-    ['proof', getArrayDecoder(fixDecoderSize(getBytesDecoder(), 32))],
+    ['args', getClaimRewardsArgsDecoder()],
   ]);
 }
 
@@ -170,6 +164,7 @@ export type ClaimRewardsAsyncInput<
   TAccountClaimState extends string = string,
   TAccountBeneficiaryTokenAccount extends string = string,
   TAccountMaybeMerkleRootAccount extends string = string,
+  TAccountMerkleRootVersion extends string = string,
   TAccountPayer extends string = string,
   TAccountRewardsTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
@@ -177,17 +172,17 @@ export type ClaimRewardsAsyncInput<
   rewardsState: Address<TAccountRewardsState>;
   rewardsVault?: Address<TAccountRewardsVault>;
   rewardsMint: Address<TAccountRewardsMint>;
-  rewardsTokenAccount: Address<TAccountRewardsTokenAccount>;
+  rewardsTokenAccount?: Address<TAccountRewardsTokenAccount>;
   owner: TransactionSigner<TAccountOwner>;
-  claimState?: Address<TAccountClaimState>;
+  claimState: Address<TAccountClaimState>;
   beneficiaryTokenAccount: Address<TAccountBeneficiaryTokenAccount>;
   maybeMerkleRootAccount?: Address<TAccountMaybeMerkleRootAccount>;
+  /** claim against this recent version instead of the current root */
+  merkleRootVersion?: Address<TAccountMerkleRootVersion>;
   payer: TransactionSigner<TAccountPayer>;
   rewardsTokenProgram: Address<TAccountRewardsTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
-  index: ClaimRewardsInstructionDataArgs['index'];
-  totalRewards: ClaimRewardsInstructionDataArgs['totalRewards'];
-  proof: ClaimRewardsInstructionDataArgs['proof'];
+  args: ClaimRewardsInstructionDataArgs['args'];
 };
 
 export async function getClaimRewardsInstructionAsync<
@@ -199,6 +194,7 @@ export async function getClaimRewardsInstructionAsync<
   TAccountClaimState extends string,
   TAccountBeneficiaryTokenAccount extends string,
   TAccountMaybeMerkleRootAccount extends string,
+  TAccountMerkleRootVersion extends string,
   TAccountPayer extends string,
   TAccountRewardsTokenProgram extends string,
   TAccountSystemProgram extends string,
@@ -213,6 +209,7 @@ export async function getClaimRewardsInstructionAsync<
     TAccountClaimState,
     TAccountBeneficiaryTokenAccount,
     TAccountMaybeMerkleRootAccount,
+    TAccountMerkleRootVersion,
     TAccountPayer,
     TAccountRewardsTokenProgram,
     TAccountSystemProgram
@@ -229,6 +226,7 @@ export async function getClaimRewardsInstructionAsync<
     TAccountClaimState,
     TAccountBeneficiaryTokenAccount,
     TAccountMaybeMerkleRootAccount,
+    TAccountMerkleRootVersion,
     TAccountPayer,
     TAccountRewardsTokenProgram,
     TAccountSystemProgram
@@ -257,6 +255,10 @@ export async function getClaimRewardsInstructionAsync<
       value: input.maybeMerkleRootAccount ?? null,
       isWritable: false,
     },
+    merkleRootVersion: {
+      value: input.merkleRootVersion ?? null,
+      isWritable: false,
+    },
     payer: { value: input.payer ?? null, isWritable: true },
     rewardsTokenProgram: {
       value: input.rewardsTokenProgram ?? null,
@@ -286,15 +288,16 @@ export async function getClaimRewardsInstructionAsync<
       ],
     });
   }
-  if (!accounts.claimState.value) {
-    accounts.claimState.value = await getProgramDerivedAddress({
-      programAddress,
+  if (!accounts.rewardsTokenAccount.value) {
+    accounts.rewardsTokenAccount.value = await getProgramDerivedAddress({
+      programAddress:
+        'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL' as Address<'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL'>,
       seeds: [
-        getBytesEncoder().encode(
-          new Uint8Array([99, 108, 97, 105, 109, 95, 115, 116, 97, 116, 101])
+        getAddressEncoder().encode(expectAddress(accounts.rewardsVault.value)),
+        getAddressEncoder().encode(
+          expectAddress(accounts.rewardsTokenProgram.value)
         ),
-        getAddressEncoder().encode(expectAddress(accounts.rewardsState.value)),
-        getAddressEncoder().encode(expectAddress(accounts.owner.value)),
+        getAddressEncoder().encode(expectAddress(accounts.rewardsMint.value)),
       ],
     });
   }
@@ -314,6 +317,7 @@ export async function getClaimRewardsInstructionAsync<
       getAccountMeta(accounts.claimState),
       getAccountMeta(accounts.beneficiaryTokenAccount),
       getAccountMeta(accounts.maybeMerkleRootAccount),
+      getAccountMeta(accounts.merkleRootVersion),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.rewardsTokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -332,6 +336,7 @@ export async function getClaimRewardsInstructionAsync<
     TAccountClaimState,
     TAccountBeneficiaryTokenAccount,
     TAccountMaybeMerkleRootAccount,
+    TAccountMerkleRootVersion,
     TAccountPayer,
     TAccountRewardsTokenProgram,
     TAccountSystemProgram
@@ -349,6 +354,7 @@ export type ClaimRewardsInput<
   TAccountClaimState extends string = string,
   TAccountBeneficiaryTokenAccount extends string = string,
   TAccountMaybeMerkleRootAccount extends string = string,
+  TAccountMerkleRootVersion extends string = string,
   TAccountPayer extends string = string,
   TAccountRewardsTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
@@ -361,12 +367,12 @@ export type ClaimRewardsInput<
   claimState: Address<TAccountClaimState>;
   beneficiaryTokenAccount: Address<TAccountBeneficiaryTokenAccount>;
   maybeMerkleRootAccount?: Address<TAccountMaybeMerkleRootAccount>;
+  /** claim against this recent version instead of the current root */
+  merkleRootVersion?: Address<TAccountMerkleRootVersion>;
   payer: TransactionSigner<TAccountPayer>;
   rewardsTokenProgram: Address<TAccountRewardsTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
-  index: ClaimRewardsInstructionDataArgs['index'];
-  totalRewards: ClaimRewardsInstructionDataArgs['totalRewards'];
-  proof: ClaimRewardsInstructionDataArgs['proof'];
+  args: ClaimRewardsInstructionDataArgs['args'];
 };
 
 export function getClaimRewardsInstruction<
@@ -378,6 +384,7 @@ export function getClaimRewardsInstruction<
   TAccountClaimState extends string,
   TAccountBeneficiaryTokenAccount extends string,
   TAccountMaybeMerkleRootAccount extends string,
+  TAccountMerkleRootVersion extends string,
   TAccountPayer extends string,
   TAccountRewardsTokenProgram extends string,
   TAccountSystemProgram extends string,
@@ -392,6 +399,7 @@ export function getClaimRewardsInstruction<
     TAccountClaimState,
     TAccountBeneficiaryTokenAccount,
     TAccountMaybeMerkleRootAccount,
+    TAccountMerkleRootVersion,
     TAccountPayer,
     TAccountRewardsTokenProgram,
     TAccountSystemProgram
@@ -407,6 +415,7 @@ export function getClaimRewardsInstruction<
   TAccountClaimState,
   TAccountBeneficiaryTokenAccount,
   TAccountMaybeMerkleRootAccount,
+  TAccountMerkleRootVersion,
   TAccountPayer,
   TAccountRewardsTokenProgram,
   TAccountSystemProgram
@@ -434,6 +443,10 @@ export function getClaimRewardsInstruction<
       value: input.maybeMerkleRootAccount ?? null,
       isWritable: false,
     },
+    merkleRootVersion: {
+      value: input.merkleRootVersion ?? null,
+      isWritable: false,
+    },
     payer: { value: input.payer ?? null, isWritable: true },
     rewardsTokenProgram: {
       value: input.rewardsTokenProgram ?? null,
@@ -466,6 +479,7 @@ export function getClaimRewardsInstruction<
       getAccountMeta(accounts.claimState),
       getAccountMeta(accounts.beneficiaryTokenAccount),
       getAccountMeta(accounts.maybeMerkleRootAccount),
+      getAccountMeta(accounts.merkleRootVersion),
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.rewardsTokenProgram),
       getAccountMeta(accounts.systemProgram),
@@ -484,6 +498,7 @@ export function getClaimRewardsInstruction<
     TAccountClaimState,
     TAccountBeneficiaryTokenAccount,
     TAccountMaybeMerkleRootAccount,
+    TAccountMerkleRootVersion,
     TAccountPayer,
     TAccountRewardsTokenProgram,
     TAccountSystemProgram
@@ -506,9 +521,11 @@ export type ParsedClaimRewardsInstruction<
     claimState: TAccountMetas[5];
     beneficiaryTokenAccount: TAccountMetas[6];
     maybeMerkleRootAccount?: TAccountMetas[7] | undefined;
-    payer: TAccountMetas[8];
-    rewardsTokenProgram: TAccountMetas[9];
-    systemProgram: TAccountMetas[10];
+    /** claim against this recent version instead of the current root */
+    merkleRootVersion?: TAccountMetas[8] | undefined;
+    payer: TAccountMetas[9];
+    rewardsTokenProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[11];
   };
   data: ClaimRewardsInstructionData;
 };
@@ -521,7 +538,7 @@ export function parseClaimRewardsInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedClaimRewardsInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -548,6 +565,7 @@ export function parseClaimRewardsInstruction<
       claimState: getNextAccount(),
       beneficiaryTokenAccount: getNextAccount(),
       maybeMerkleRootAccount: getNextOptionalAccount(),
+      merkleRootVersion: getNextOptionalAccount(),
       payer: getNextAccount(),
       rewardsTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
@@ -6,8 +6,14 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './acceptAdmin';
+export * from './addRewardsMint';
 export * from './claimRewards';
+export * from './claimRewardsFor';
 export * from './initialize';
 export * from './initializeRewardsState';
+export * from './migrateGlobalConfig';
+export * from './migrateRewardsState';
+export * from './proposeAdmin';
 export * from './updateAuthority';
 export * from './updateMerkleRoot';
@@ -14,6 +14,10 @@ import {
   getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type Address,
   type Codec,
@@ -24,10 +28,12 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { DEPHY_REWARDS_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -52,6 +58,11 @@ export type UpdateMerkleRootInstruction<
   TProgram extends string = typeof DEPHY_REWARDS_PROGRAM_ADDRESS,
   TAccountRewardsState extends string | IAccountMeta<string> = string,
   TAccountAuthority extends string | IAccountMeta<string> = string,
+  TAccountMerkleRootVersion extends string | IAccountMeta<string> = string,
+  TAccountPayer extends string | IAccountMeta<string> = string,
+  TAccountSystemProgram extends
+    | string
+    | IAccountMeta<string> = '11111111111111111111111111111111',
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -64,6 +75,16 @@ export type UpdateMerkleRootInstruction<
         ? ReadonlySignerAccount<TAccountAuthority> &
             IAccountSignerMeta<TAccountAuthority>
         : TAccountAuthority,
+      TAccountMerkleRootVersion extends string
+        ? WritableAccount<TAccountMerkleRootVersion>
+        : TAccountMerkleRootVersion,
+      TAccountPayer extends string
+        ? WritableSignerAccount<TAccountPayer> &
+            IAccountSignerMeta<TAccountPayer>
+        : TAccountPayer,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -71,10 +92,16 @@ export type UpdateMerkleRootInstruction<
 export type UpdateMerkleRootInstructionData = {
   discriminator: ReadonlyUint8Array;
   merkleRoot: MerkleRoot;
+  /** sum of the leaf totals, informational */
+  totalAmount: bigint;
+  leafCount: number;
 };
 
 export type UpdateMerkleRootInstructionDataArgs = {
   merkleRoot: MerkleRootArgs;
+  /** sum of the leaf totals, informational */
+  totalAmount: number | bigint;
+  leafCount: number;
 };
 
 export function getUpdateMerkleRootInstructionDataEncoder(): Encoder<UpdateMerkleRootInstructionDataArgs> {
@@ -82,6 +109,8 @@ export function getUpdateMerkleRootInstructionDataEncoder(): Encoder<UpdateMerkl
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['merkleRoot', getMerkleRootEncoder()],
+      ['totalAmount', getU64Encoder()],
+      ['leafCount', getU32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: UPDATE_MERKLE_ROOT_DISCRIMINATOR })
   );
@@ -91,6 +120,8 @@ export function getUpdateMerkleRootInstructionDataDecoder(): Decoder<UpdateMerkl
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['merkleRoot', getMerkleRootDecoder()],
+    ['totalAmount', getU64Decoder()],
+    ['leafCount', getU32Decoder()],
   ]);
 }
 
@@ -107,23 +138,44 @@ export function getUpdateMerkleRootInstructionDataCodec(): Codec<
 export type UpdateMerkleRootInput<
   TAccountRewardsState extends string = string,
   TAccountAuthority extends string = string,
+  TAccountMerkleRootVersion extends string = string,
+  TAccountPayer extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   rewardsState: Address<TAccountRewardsState>;
   authority: TransactionSigner<TAccountAuthority>;
+  merkleRootVersion: Address<TAccountMerkleRootVersion>;
+  payer: TransactionSigner<TAccountPayer>;
+  systemProgram?: Address<TAccountSystemProgram>;
   merkleRoot: UpdateMerkleRootInstructionDataArgs['merkleRoot'];
+  /** sum of the leaf totals, informational */
+  totalAmount: UpdateMerkleRootInstructionDataArgs['totalAmount'];
+  leafCount: UpdateMerkleRootInstructionDataArgs['leafCount'];
 };
 
 export function getUpdateMerkleRootInstruction<
   TAccountRewardsState extends string,
   TAccountAuthority extends string,
+  TAccountMerkleRootVersion extends string,
+  TAccountPayer extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof DEPHY_REWARDS_PROGRAM_ADDRESS,
 >(
-  input: UpdateMerkleRootInput<TAccountRewardsState, TAccountAuthority>,
+  input: UpdateMerkleRootInput<
+    TAccountRewardsState,
+    TAccountAuthority,
+    TAccountMerkleRootVersion,
+    TAccountPayer,
+    TAccountSystemProgram
+  >,
   config?: { programAddress?: TProgramAddress }
 ): UpdateMerkleRootInstruction<
   TProgramAddress,
   TAccountRewardsState,
-  TAccountAuthority
+  TAccountAuthority,
+  TAccountMerkleRootVersion,
+  TAccountPayer,
+  TAccountSystemProgram
 > {
   // Program address.
   const programAddress =
@@ -133,6 +185,12 @@ export function getUpdateMerkleRootInstruction<
   const originalAccounts = {
     rewardsState: { value: input.rewardsState ?? null, isWritable: true },
     authority: { value: input.authority ?? null, isWritable: false },
+    merkleRootVersion: {
+      value: input.merkleRootVersion ?? null,
+      isWritable: true,
+    },
+    payer: { value: input.payer ?? null, isWritable: true },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -142,11 +200,20 @@ export function getUpdateMerkleRootInstruction<
   // Original args.
   const args = { ...input };
 
+  // Resolve default values.
+  if (!accounts.systemProgram.value) {
+    accounts.systemProgram.value =
+      '11111111111111111111111111111111' as Address<'11111111111111111111111111111111'>;
+  }
+
   const getAccountMeta = getAccountMetaFactory(programAddress, 'programId');
   const instruction = {
     accounts: [
       getAccountMeta(accounts.rewardsState),
       getAccountMeta(accounts.authority),
+      getAccountMeta(accounts.merkleRootVersion),
+      getAccountMeta(accounts.payer),
+      getAccountMeta(accounts.systemProgram),
     ],
     programAddress,
     data: getUpdateMerkleRootInstructionDataEncoder().encode(
@@ -155,7 +222,10 @@ export function getUpdateMerkleRootInstruction<
   } as UpdateMerkleRootInstruction<
     TProgramAddress,
     TAccountRewardsState,
-    TAccountAuthority
+    TAccountAuthority,
+    TAccountMerkleRootVersion,
+    TAccountPayer,
+    TAccountSystemProgram
   >;
 
   return instruction;
@@ -169,6 +239,9 @@ export type ParsedUpdateMerkleRootInstruction<
   accounts: {
     rewardsState: TAccountMetas[0];
     authority: TAccountMetas[1];
+    merkleRootVersion: TAccountMetas[2];
+    payer: TAccountMetas[3];
+    systemProgram: TAccountMetas[4];
   };
   data: UpdateMerkleRootInstructionData;
 };
@@ -181,7 +254,7 @@ export function parseUpdateMerkleRootInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedUpdateMerkleRootInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -196,6 +269,9 @@ export function parseUpdateMerkleRootInstruction<
     accounts: {
       rewardsState: getNextAccount(),
       authority: getNextAccount(),
+      merkleRootVersion: getNextAccount(),
+      payer: getNextAccount(),
+      systemProgram: getNextAccount(),
     },
     data: getUpdateMerkleRootInstructionDataDecoder().decode(instruction.data),
   };
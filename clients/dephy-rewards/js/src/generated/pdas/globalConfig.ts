@@ -17,7 +17,7 @@ export async function findGlobalConfigPda(
   config: { programAddress?: Address | undefined } = {}
 ): Promise<ProgramDerivedAddress> {
   const {
-    programAddress = 'PHYRvUPmU31VoVgoiK9RRgwtKTnejV5v4QfBvvXXTmV' as Address<'PHYRvUPmU31VoVgoiK9RRgwtKTnejV5v4QfBvvXXTmV'>,
+    programAddress = 'BEQB5zna1N4eXTGPLdAVG9HJ1bL8rXSMrR7FdycJ6Zd9' as Address<'BEQB5zna1N4eXTGPLdAVG9HJ1bL8rXSMrR7FdycJ6Zd9'>,
   } = config;
   return await getProgramDerivedAddress({
     programAddress,
@@ -7,5 +7,7 @@
  */
 
 export * from './claimState';
+export * from './extraMintClaimState';
 export * from './globalConfig';
+export * from './merkleRootVersion';
 export * from './rewardsVault';
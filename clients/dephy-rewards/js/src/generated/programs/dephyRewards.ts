@@ -14,9 +14,15 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAcceptAdminInstruction,
+  type ParsedAddRewardsMintInstruction,
+  type ParsedClaimRewardsForInstruction,
   type ParsedClaimRewardsInstruction,
   type ParsedInitializeInstruction,
   type ParsedInitializeRewardsStateInstruction,
+  type ParsedMigrateGlobalConfigInstruction,
+  type ParsedMigrateRewardsStateInstruction,
+  type ParsedProposeAdminInstruction,
   type ParsedUpdateAuthorityInstruction,
   type ParsedUpdateMerkleRootInstruction,
 } from '../instructions';
@@ -27,6 +33,7 @@ export const DEPHY_REWARDS_PROGRAM_ADDRESS =
 export enum DephyRewardsAccount {
   ClaimState,
   GlobalConfig,
+  MerkleRootVersion,
   RewardsState,
 }
 
@@ -56,6 +63,17 @@ export function identifyDephyRewardsAccount(
   ) {
     return DephyRewardsAccount.GlobalConfig;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([146, 54, 244, 213, 215, 168, 196, 121])
+      ),
+      0
+    )
+  ) {
+    return DephyRewardsAccount.MerkleRootVersion;
+  }
   if (
     containsBytes(
       data,
@@ -73,9 +91,15 @@ export function identifyDephyRewardsAccount(
 }
 
 export enum DephyRewardsInstruction {
+  AcceptAdmin,
+  AddRewardsMint,
   ClaimRewards,
+  ClaimRewardsFor,
   Initialize,
   InitializeRewardsState,
+  MigrateGlobalConfig,
+  MigrateRewardsState,
+  ProposeAdmin,
   UpdateAuthority,
   UpdateMerkleRoot,
 }
@@ -84,6 +108,28 @@ export function identifyDephyRewardsInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): DephyRewardsInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 42, 45, 90, 116, 181, 13, 170])
+      ),
+      0
+    )
+  ) {
+    return DephyRewardsInstruction.AcceptAdmin;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([189, 33, 237, 155, 238, 85, 219, 142])
+      ),
+      0
+    )
+  ) {
+    return DephyRewardsInstruction.AddRewardsMint;
+  }
   if (
     containsBytes(
       data,
@@ -95,6 +141,17 @@ export function identifyDephyRewardsInstruction(
   ) {
     return DephyRewardsInstruction.ClaimRewards;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([36, 211, 252, 62, 221, 26, 188, 184])
+      ),
+      0
+    )
+  ) {
+    return DephyRewardsInstruction.ClaimRewardsFor;
+  }
   if (
     containsBytes(
       data,
@@ -117,6 +174,39 @@ export function identifyDephyRewardsInstruction(
   ) {
     return DephyRewardsInstruction.InitializeRewardsState;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([207, 52, 247, 7, 1, 230, 228, 147])
+      ),
+      0
+    )
+  ) {
+    return DephyRewardsInstruction.MigrateGlobalConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([138, 197, 228, 194, 238, 150, 104, 108])
+      ),
+      0
+    )
+  ) {
+    return DephyRewardsInstruction.MigrateRewardsState;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([121, 214, 199, 212, 87, 39, 117, 234])
+      ),
+      0
+    )
+  ) {
+    return DephyRewardsInstruction.ProposeAdmin;
+  }
   if (
     containsBytes(
       data,
@@ -147,15 +237,33 @@ export function identifyDephyRewardsInstruction(
 export type ParsedDephyRewardsInstruction<
   TProgram extends string = 'BEQB5zna1N4eXTGPLdAVG9HJ1bL8rXSMrR7FdycJ6Zd9',
 > =
+  | ({
+      instructionType: DephyRewardsInstruction.AcceptAdmin;
+    } & ParsedAcceptAdminInstruction<TProgram>)
+  | ({
+      instructionType: DephyRewardsInstruction.AddRewardsMint;
+    } & ParsedAddRewardsMintInstruction<TProgram>)
   | ({
       instructionType: DephyRewardsInstruction.ClaimRewards;
     } & ParsedClaimRewardsInstruction<TProgram>)
+  | ({
+      instructionType: DephyRewardsInstruction.ClaimRewardsFor;
+    } & ParsedClaimRewardsForInstruction<TProgram>)
   | ({
       instructionType: DephyRewardsInstruction.Initialize;
     } & ParsedInitializeInstruction<TProgram>)
   | ({
       instructionType: DephyRewardsInstruction.InitializeRewardsState;
     } & ParsedInitializeRewardsStateInstruction<TProgram>)
+  | ({
+      instructionType: DephyRewardsInstruction.MigrateGlobalConfig;
+    } & ParsedMigrateGlobalConfigInstruction<TProgram>)
+  | ({
+      instructionType: DephyRewardsInstruction.MigrateRewardsState;
+    } & ParsedMigrateRewardsStateInstruction<TProgram>)
+  | ({
+      instructionType: DephyRewardsInstruction.ProposeAdmin;
+    } & ParsedProposeAdminInstruction<TProgram>)
   | ({
       instructionType: DephyRewardsInstruction.UpdateAuthority;
     } & ParsedUpdateAuthorityInstruction<TProgram>)
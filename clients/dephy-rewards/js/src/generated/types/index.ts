@@ -6,4 +6,12 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './adminAccepted';
+export * from './adminProposed';
+export * from './authorityUpdated';
+export * from './claimRewardsArgs';
 export * from './merkleRoot';
+export * from './merkleRootUpdated';
+export * from './rewardsClaimed';
+export * from './rewardsMintAdded';
+export * from './vestingSchedule';
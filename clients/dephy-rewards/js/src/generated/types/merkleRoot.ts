@@ -31,11 +31,13 @@ import {
 
 export type MerkleRoot =
   | { __kind: 'Inplace'; hash: ReadonlyUint8Array }
-  | { __kind: 'External'; pubkey: Address; offset: bigint };
+  | { __kind: 'External'; pubkey: Address; offset: bigint }
+  | { __kind: 'NcnBallotBox'; config: Address; round: bigint };
 
 export type MerkleRootArgs =
   | { __kind: 'Inplace'; hash: ReadonlyUint8Array }
-  | { __kind: 'External'; pubkey: Address; offset: number | bigint };
+  | { __kind: 'External'; pubkey: Address; offset: number | bigint }
+  | { __kind: 'NcnBallotBox'; config: Address; round: number | bigint };
 
 export function getMerkleRootEncoder(): Encoder<MerkleRootArgs> {
   return getDiscriminatedUnionEncoder([
@@ -50,6 +52,13 @@ export function getMerkleRootEncoder(): Encoder<MerkleRootArgs> {
         ['offset', getU64Encoder()],
       ]),
     ],
+    [
+      'NcnBallotBox',
+      getStructEncoder([
+        ['config', getAddressEncoder()],
+        ['round', getU64Encoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -66,6 +75,13 @@ export function getMerkleRootDecoder(): Decoder<MerkleRoot> {
         ['offset', getU64Decoder()],
       ]),
     ],
+    [
+      'NcnBallotBox',
+      getStructDecoder([
+        ['config', getAddressDecoder()],
+        ['round', getU64Decoder()],
+      ]),
+    ],
   ]);
 }
 
@@ -86,6 +102,14 @@ export function merkleRoot(
     'External'
   >
 ): GetDiscriminatedUnionVariant<MerkleRootArgs, '__kind', 'External'>;
+export function merkleRoot(
+  kind: 'NcnBallotBox',
+  data: GetDiscriminatedUnionVariantContent<
+    MerkleRootArgs,
+    '__kind',
+    'NcnBallotBox'
+  >
+): GetDiscriminatedUnionVariant<MerkleRootArgs, '__kind', 'NcnBallotBox'>;
 export function merkleRoot<K extends MerkleRootArgs['__kind'], Data>(
   kind: K,
   data?: Data
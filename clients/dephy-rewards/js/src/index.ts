@@ -1,3 +1,4 @@
+export * from './claim-message';
 export * from './generated';
 export * from './merkle-tree';
 export * from './rewards-tree';
@@ -1,17 +1,33 @@
 import { MerkleTree, hash } from "./merkle-tree";
 import { Address, getAddressCodec } from "gill";
+import { VestingSchedule } from "./generated";
 
 const addressCodec = getAddressCodec()
 
 export type RewardsNode = {
   user: Address;
+  // unset for the primary rewards mint of the rewards state
+  mint?: Address;
   amount: bigint;
+  vesting?: VestingSchedule;
 }
 
 export function hashNode(node: RewardsNode): Buffer {
   const amountBuffer = Buffer.alloc(8);
   amountBuffer.writeBigUInt64LE(node.amount, 0);
-  return hash(Buffer.from(addressCodec.encode(node.user)), amountBuffer);
+  // same as `dephy_rewards::hash_leaf`
+  const data = [amountBuffer];
+  if (node.mint) {
+    data.unshift(Buffer.from(addressCodec.encode(node.mint)));
+  }
+  if (node.vesting) {
+    const vestingBuffer = Buffer.alloc(24);
+    vestingBuffer.writeBigInt64LE(node.vesting.startTs, 0);
+    vestingBuffer.writeBigInt64LE(node.vesting.cliffTs, 8);
+    vestingBuffer.writeBigInt64LE(node.vesting.endTs, 16);
+    data.push(vestingBuffer);
+  }
+  return hash(Buffer.from(addressCodec.encode(node.user)), Buffer.concat(data));
 }
 
 export function buildRewardsTree(nodes: RewardsNode[]) {
@@ -1,6 +1,7 @@
 import {
   addPdasVisitor, 
   constantPdaSeedNodeFromString,
+  numberTypeNode,
   publicKeyTypeNode, 
   variablePdaSeedNode,
 } from 'codama';
@@ -25,6 +26,21 @@ export const pdas = addPdasVisitor({
       variablePdaSeedNode('rewards_state', publicKeyTypeNode()),
       variablePdaSeedNode('user', publicKeyTypeNode()),
     ]
+  }, {
+    name: 'extraMintClaimState',
+    seeds: [
+      constantPdaSeedNodeFromString('utf8', "claim_state"),
+      variablePdaSeedNode('rewards_state', publicKeyTypeNode()),
+      variablePdaSeedNode('user', publicKeyTypeNode()),
+      variablePdaSeedNode('mint', publicKeyTypeNode()),
+    ]
+  }, {
+    name: 'merkleRootVersion',
+    seeds: [
+      constantPdaSeedNodeFromString('utf8', "merkle_root_version"),
+      variablePdaSeedNode('rewards_state', publicKeyTypeNode()),
+      variablePdaSeedNode('version', numberTypeNode('u64')),
+    ]
   }]
 })
 
@@ -10,6 +10,10 @@ pub const SEED_DEPHY_NCN: &[u8] = b"dephy_ncn";
 #[constant]
 pub const SEED_BALLOT_BOX: &[u8] = b"ballot_box";
 #[constant]
+pub const SEED_EPOCH_SNAPSHOT: &[u8] = b"epoch_snapshot";
+#[constant]
+pub const SEED_OPERATOR_SNAPSHOT: &[u8] = b"operator_snapshot";
+#[constant]
 pub const SEED_NCN_ADMIN: &[u8] = b"ncn_admin";
 #[constant]
 pub const SEED_NCN_OPERATOR_STATE: &[u8] = b"ncn_operator_state";
@@ -18,10 +22,40 @@ pub const SEED_VOTER_STATE: &[u8] = b"voter_state";
 #[constant]
 pub const SEED_NCN_VAULT_TICKET: &[u8] = b"ncn_vault_ticket";
 #[constant]
+pub const SEED_VAULT_NCN_TICKET: &[u8] = b"vault_ncn_ticket";
+#[constant]
 pub const SEED_OPERATOR_VAULT_TICKET: &[u8] = b"operator_vault_ticket";
 #[constant]
 pub const SEED_VAULT_OPERATOR_DELEGATION: &[u8] = b"vault_operator_delegation";
 #[constant]
+pub const SEED_NCN_VAULT_SLASHER_TICKET: &[u8] = b"ncn_vault_slasher_ticket";
+#[constant]
+pub const SEED_OFFENCE_LEDGER: &[u8] = b"offence_ledger";
+#[constant]
+pub const SEED_JOIN_REQUEST: &[u8] = b"join_request";
+#[constant]
 pub const SEED_REWARDS_STATE: &[u8] = b"rewards_state";
 #[constant]
 pub const SEED_REWARDS_VAULT: &[u8] = b"rewards_vault";
+
+// Limits
+#[constant]
+pub const MAX_SNAPSHOT_VAULTS: usize = 32;
+#[constant]
+pub const MAX_BALLOTS: usize = 16;
+#[constant]
+pub const MAX_OFFENCES: usize = 16;
+#[constant]
+pub const MAX_GUARDIANS: usize = 8;
+
+// Consensus
+#[constant]
+pub const BPS_DENOMINATOR: u16 = 10_000;
+#[constant]
+pub const DEFAULT_CONSENSUS_THRESHOLD_BPS: u16 = 6_667;
+#[constant]
+pub const DEFAULT_MIN_VOTERS: u64 = 1;
+#[constant]
+pub const DEFAULT_BALLOT_RETENTION_ROUNDS: u64 = 32;
+#[constant]
+pub const DEFAULT_CHALLENGE_PERIOD_SLOTS: u64 = 9_000;
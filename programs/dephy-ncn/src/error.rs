@@ -6,20 +6,78 @@ pub enum DephyNcnError {
     ConfigMismatch,
     #[msg("Invalid authority")]
     InvalidAuthority,
+    #[msg("Invalid config")]
+    InvalidConfig,
     #[msg("Invalid vault")]
     InvalidVault,
     #[msg("Invalid operator")]
     InvalidOperator,
+    #[msg("Invalid voter")]
+    InvalidVoter,
     #[msg("Invalid operator vault ticket")]
     InvalidOperatorVaultTicket,
     #[msg("Invalid vault operator delegation")]
     InvalidVaultOperatorDelegation,
-    #[msg("Invalid epoch")]
-    InvalidEpoch,
+    #[msg("Invalid round")]
+    InvalidRound,
     #[msg("Proposed rewards root already exists")]
     NonEmptyProposedRoot,
     #[msg("No proposed rewards root")]
     EmptyProposedRoot,
     #[msg("No delegation")]
     NoDelegation,
+    #[msg("Invalid ncn vault ticket")]
+    InvalidNcnVaultTicket,
+    #[msg("Invalid vault ncn ticket")]
+    InvalidVaultNcnTicket,
+    #[msg("Invalid ncn operator state")]
+    InvalidNcnOperatorState,
+    #[msg("Operator not active for ncn")]
+    OperatorNotActive,
+    #[msg("Invalid remaining accounts")]
+    InvalidRemainingAccounts,
+    #[msg("Invalid vault index")]
+    InvalidVaultIndex,
+    #[msg("Too many vaults")]
+    TooManyVaults,
+    #[msg("Too many proposed rewards roots")]
+    TooManyBallots,
+    #[msg("Epoch snapshot incomplete")]
+    EpochSnapshotIncomplete,
+    #[msg("Ballot box still retained")]
+    BallotBoxRetained,
+    #[msg("Consensus not reached")]
+    ConsensusNotReached,
+    #[msg("Rewards root already published")]
+    AlreadyPublished,
+    #[msg("Round already settled")]
+    RoundAlreadySettled,
+    #[msg("Consensus already reached")]
+    ConsensusAlreadyReached,
+    #[msg("Already voted for this root")]
+    AlreadyVoted,
+    #[msg("Invalid round status")]
+    InvalidRoundStatus,
+    #[msg("Challenge period not over")]
+    ChallengePeriodNotOver,
+    #[msg("Challenge period over")]
+    ChallengePeriodOver,
+    #[msg("Needs the authority or an approved join request")]
+    OnboardingNotAllowed,
+    #[msg("Applicant is not the admin")]
+    InvalidApplicant,
+    #[msg("Join request already reviewed")]
+    JoinRequestReviewed,
+    #[msg("Operator not in the round snapshot")]
+    OperatorNotInSnapshot,
+    #[msg("Snapshot still retained")]
+    SnapshotRetained,
+    #[msg("Operator round not settled")]
+    RoundNotSettled,
+    #[msg("Ballot box not closed")]
+    BallotBoxNotClosed,
+    #[msg("Join request payer mismatch")]
+    JoinRequestPayerMismatch,
+    #[msg("Operator snapshot already complete")]
+    OperatorSnapshotComplete,
 }
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{JoinKind, NcnRole};
+
+#[event]
+pub struct VaultInitialized {
+    pub config: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct VaultWarmedUp {
+    pub config: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct VaultCooledDown {
+    pub config: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct VaultSlasherCooledDown {
+    pub config: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct OperatorInitialized {
+    pub config: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct OperatorWarmedUp {
+    pub config: Pubkey,
+    pub operator: Pubkey,
+}
+
+#[event]
+pub struct OperatorCooledDown {
+    pub config: Pubkey,
+    pub operator: Pubkey,
+}
+
+/// The jito ncn `role` was handed to `new_admin`
+#[event]
+pub struct NcnAdminSet {
+    pub config: Pubkey,
+    pub role: NcnRole,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct JoinRequested {
+    pub config: Pubkey,
+    pub kind: JoinKind,
+    pub target: Pubkey,
+    pub applicant: Pubkey,
+}
+
+#[event]
+pub struct JoinRequestReviewed {
+    pub config: Pubkey,
+    pub kind: JoinKind,
+    pub target: Pubkey,
+    pub approved: bool,
+}
+
+/// A new round opened for votes, with its epoch snapshot and ballot box
+#[event]
+pub struct RoundStarted {
+    pub config: Pubkey,
+    pub round: u64,
+    pub operator_count: u64,
+    pub vault_count: u64,
+}
+
+#[event]
+pub struct Voted {
+    pub config: Pubkey,
+    pub operator: Pubkey,
+    pub round: u64,
+    pub rewards_root: [u8; 32],
+    pub stake: u64,
+}
+
+#[event]
+pub struct Revoted {
+    pub config: Pubkey,
+    pub operator: Pubkey,
+    pub round: u64,
+    pub previous_root: [u8; 32],
+    pub rewards_root: [u8; 32],
+    pub stake: u64,
+}
+
+#[event]
+pub struct ConsensusReached {
+    pub config: Pubkey,
+    pub round: u64,
+    pub rewards_root: [u8; 32],
+    /// stake voted for the winning root
+    pub stake: u64,
+    pub total_stake: u64,
+}
+
+#[event]
+pub struct RoundVetoed {
+    pub config: Pubkey,
+    pub round: u64,
+    pub rewards_root: [u8; 32],
+    pub vetoed_by: Pubkey,
+}
+
+#[event]
+pub struct RoundFinalized {
+    pub config: Pubkey,
+    pub round: u64,
+    pub rewards_root: [u8; 32],
+}
+
+#[event]
+pub struct RewardsRootPublished {
+    pub config: Pubkey,
+    pub round: u64,
+    pub rewards_state: Pubkey,
+    pub rewards_root: [u8; 32],
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::DephyNcnError, state::Config};
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(mut)]
+    pub config: Account<'info, Config>,
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handle_accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        config.pending_authority == Some(ctx.accounts.new_authority.key()),
+        DephyNcnError::InvalidAuthority
+    );
+
+    config.authority = ctx.accounts.new_authority.key();
+    config.pending_authority = None;
+
+    Ok(())
+}
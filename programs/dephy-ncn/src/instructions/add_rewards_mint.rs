@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenInterface},
+};
+use dephy_rewards::{program::DephyRewards, RewardsState};
+
+use crate::{constants::*, error::DephyNcnError, state::Config};
+
+#[derive(Accounts)]
+pub struct AddRewardsMint<'info> {
+    #[account(has_one = authority @ DephyNcnError::InvalidAuthority,
+        seeds = [SEED_DEPHY_NCN, config.ncn.as_ref()], bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [SEED_REWARDS_STATE, config.key().as_ref()], bump)]
+    pub rewards_state: Account<'info, RewardsState>,
+    #[account(seeds = [SEED_REWARDS_VAULT, rewards_state.key().as_ref()], bump, seeds::program = rewards_program)]
+    pub rewards_vault: SystemAccount<'info>,
+    #[account(mint::token_program = rewards_token_program)]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+    /// CHECK: checked in rewards program
+    #[account(mut)]
+    pub rewards_token_account: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub rewards_program: Program<'info, DephyRewards>,
+    pub rewards_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
+
+pub fn handle_add_rewards_mint(ctx: Context<AddRewardsMint>) -> Result<()> {
+    dephy_rewards::cpi::add_rewards_mint(CpiContext::new_with_signer(
+        ctx.accounts.rewards_program.to_account_info(),
+        dephy_rewards::cpi::accounts::AddRewardsMint {
+            rewards_state: ctx.accounts.rewards_state.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+            rewards_vault: ctx.accounts.rewards_vault.to_account_info(),
+            rewards_mint: ctx.accounts.rewards_mint.to_account_info(),
+            rewards_token_account: ctx.accounts.rewards_token_account.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            rewards_token_program: ctx.accounts.rewards_token_program.to_account_info(),
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        &[&[
+            SEED_DEPHY_NCN,
+            ctx.accounts.config.ncn.as_ref(),
+            &[ctx.bumps.config],
+        ]],
+    ))
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{accounts::Config as RestakingConfig, programs::JITO_RESTAKING_ID};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    state::{BallotBox, Config, EpochSnapshot},
+};
+
+/// Claims of dephy-rewards states using `MerkleRoot::NcnBallotBox` for the round fail once
+/// it is closed, they have `ballot_retention_rounds` rounds to move to a newer round
+#[derive(Accounts)]
+pub struct CloseBallotBox<'info> {
+    pub config: Account<'info, Config>,
+    #[account(seeds = [SEED_EPOCH_SNAPSHOT, config.key().as_ref(), &ballot_box.round.to_le_bytes()], bump)]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+    #[account(mut, close = payer, has_one = config @ DephyNcnError::ConfigMismatch, has_one = payer,
+        seeds = [SEED_BALLOT_BOX, config.key().as_ref(), &ballot_box.round.to_le_bytes()], bump
+    )]
+    pub ballot_box: Account<'info, BallotBox>,
+    /// CHECK: rent receiver recorded in the ballot box
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_ballot_box(ctx: Context<CloseBallotBox>) -> Result<()> {
+    let clock = Clock::get()?;
+    let restaking_config =
+        RestakingConfig::from_bytes(&ctx.accounts.jito_restaking_config.try_borrow_data()?[8..])?;
+    let config = &ctx.accounts.config;
+    let round = config
+        .voting_period
+        .round(clock.slot, restaking_config.epoch_length);
+
+    // the round can not be voted again, its epoch snapshot is still there
+    require!(
+        !config.is_retained(ctx.accounts.ballot_box.round, round),
+        DephyNcnError::BallotBoxRetained
+    );
+    // settling reads the ballot box
+    require!(
+        ctx.accounts.epoch_snapshot.is_settled(),
+        DephyNcnError::RoundNotSettled
+    );
+
+    Ok(())
+}
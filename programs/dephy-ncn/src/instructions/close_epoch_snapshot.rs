@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{accounts::Config as RestakingConfig, programs::JITO_RESTAKING_ID};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    state::{Config, EpochSnapshot},
+};
+
+#[derive(Accounts)]
+pub struct CloseEpochSnapshot<'info> {
+    pub config: Account<'info, Config>,
+    #[account(mut, close = payer, has_one = config @ DephyNcnError::ConfigMismatch, has_one = payer,
+        seeds = [SEED_EPOCH_SNAPSHOT, config.key().as_ref(), &epoch_snapshot.round.to_le_bytes()], bump
+    )]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+    /// CHECK: must be closed first, it checks the round is settled against the epoch snapshot
+    #[account(seeds = [SEED_BALLOT_BOX, config.key().as_ref(), &epoch_snapshot.round.to_le_bytes()], bump)]
+    pub ballot_box: UncheckedAccount<'info>,
+    /// CHECK: rent receiver recorded in the epoch snapshot
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_epoch_snapshot(ctx: Context<CloseEpochSnapshot>) -> Result<()> {
+    let clock = Clock::get()?;
+    let restaking_config =
+        RestakingConfig::from_bytes(&ctx.accounts.jito_restaking_config.try_borrow_data()?[8..])?;
+    let config = &ctx.accounts.config;
+    let round = config
+        .voting_period
+        .round(clock.slot, restaking_config.epoch_length);
+
+    require!(
+        !config.is_retained(ctx.accounts.epoch_snapshot.round, round),
+        DephyNcnError::SnapshotRetained
+    );
+    require!(
+        ctx.accounts.ballot_box.data_is_empty(),
+        DephyNcnError::BallotBoxNotClosed
+    );
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{accounts::Config as RestakingConfig, programs::JITO_RESTAKING_ID};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    state::{Config, OperatorSnapshot},
+};
+
+#[derive(Accounts)]
+pub struct CloseOperatorSnapshot<'info> {
+    pub config: Account<'info, Config>,
+    /// CHECK: only binds the operator snapshot to the config, may be closed already
+    #[account(seeds = [SEED_EPOCH_SNAPSHOT, config.key().as_ref(), &operator_snapshot.round.to_le_bytes()], bump)]
+    pub epoch_snapshot: UncheckedAccount<'info>,
+    #[account(mut, close = payer, has_one = payer,
+        seeds = [SEED_OPERATOR_SNAPSHOT, epoch_snapshot.key().as_ref(), operator_snapshot.operator.as_ref()], bump
+    )]
+    pub operator_snapshot: Account<'info, OperatorSnapshot>,
+    /// CHECK: rent receiver recorded in the operator snapshot
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_operator_snapshot(ctx: Context<CloseOperatorSnapshot>) -> Result<()> {
+    let operator_snapshot = &ctx.accounts.operator_snapshot;
+    // the voter state and offence ledger must not miss the round
+    require!(operator_snapshot.settled, DephyNcnError::RoundNotSettled);
+
+    let clock = Clock::get()?;
+    let restaking_config =
+        RestakingConfig::from_bytes(&ctx.accounts.jito_restaking_config.try_borrow_data()?[8..])?;
+    let config = &ctx.accounts.config;
+    let round = config
+        .voting_period
+        .round(clock.slot, restaking_config.epoch_length);
+
+    require!(
+        !config.is_retained(operator_snapshot.round, round),
+        DephyNcnError::SnapshotRetained
+    );
+
+    Ok(())
+}
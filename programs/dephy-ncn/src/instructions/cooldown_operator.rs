@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::JitoRestaking;
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::OperatorCooledDown,
+    state::{Config, JoinRequest},
+};
+
+#[derive(Accounts)]
+pub struct CooldownOperator<'info> {
+    #[account(has_one = authority @ DephyNcnError::InvalidAuthority)]
+    pub config: Account<'info, Config>,
+    /// CHECK:
+    #[account(address = config.ncn)]
+    pub ncn: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_NCN_ADMIN, ncn.key().as_ref()], bump)]
+    pub ncn_admin: SystemAccount<'info>,
+    /// CHECK:
+    pub operator: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = jito_restaking_program)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(mut, seeds = [SEED_NCN_OPERATOR_STATE, ncn.key.as_ref(), operator.key.as_ref()], seeds::program = jito_restaking_program, bump)]
+    pub ncn_operator_state: UncheckedAccount<'info>,
+    /// CHECK: a join request of the operator, if any, is revoked
+    #[account(mut, seeds = [SEED_JOIN_REQUEST, config.key().as_ref(), operator.key().as_ref()], bump)]
+    pub join_request: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    pub jito_restaking_program: Program<'info, JitoRestaking>,
+}
+
+pub fn handle_cooldown_operator(ctx: Context<CooldownOperator>) -> Result<()> {
+    jito_restaking_client::instructions::NcnCooldownOperatorCpi::new(
+        &ctx.accounts.jito_restaking_program,
+        jito_restaking_client::instructions::NcnCooldownOperatorCpiAccounts {
+            config: &ctx.accounts.jito_restaking_config.to_account_info(),
+            ncn: &ctx.accounts.ncn.to_account_info(),
+            operator: &ctx.accounts.operator.to_account_info(),
+            ncn_operator_state: &ctx.accounts.ncn_operator_state.to_account_info(),
+            admin: &ctx.accounts.ncn_admin.to_account_info(),
+        },
+    )
+    .invoke_signed(&[&[
+        SEED_NCN_ADMIN,
+        ctx.accounts.ncn.key().as_ref(),
+        &[ctx.bumps.ncn_admin],
+    ]])?;
+
+    JoinRequest::revoke(&ctx.accounts.join_request)?;
+
+    emit!(OperatorCooledDown {
+        config: ctx.accounts.config.key(),
+        operator: ctx.accounts.operator.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::JitoRestaking;
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::VaultCooledDown,
+    state::{Config, JoinRequest},
+};
+
+#[derive(Accounts)]
+pub struct CooldownVault<'info> {
+    #[account(has_one = authority @ DephyNcnError::InvalidAuthority)]
+    pub config: Account<'info, Config>,
+    /// CHECK:
+    #[account(address = config.ncn)]
+    pub ncn: UncheckedAccount<'info>,
+    /// CHECK:
+    pub vault: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [SEED_NCN_VAULT_TICKET, ncn.key.as_ref(), vault.key.as_ref()],
+        seeds::program = jito_restaking_program, bump
+    )]
+    pub ncn_vault_ticket: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = jito_restaking_program)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_NCN_ADMIN, ncn.key().as_ref()], bump)]
+    pub ncn_admin: SystemAccount<'info>,
+    /// CHECK: a join request of the vault, if any, is revoked
+    #[account(mut, seeds = [SEED_JOIN_REQUEST, config.key().as_ref(), vault.key().as_ref()], bump)]
+    pub join_request: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    pub jito_restaking_program: Program<'info, JitoRestaking>,
+}
+
+pub fn handle_cooldown_vault(ctx: Context<CooldownVault>) -> Result<()> {
+    jito_restaking_client::instructions::CooldownNcnVaultTicketCpi::new(
+        &ctx.accounts.jito_restaking_program,
+        jito_restaking_client::instructions::CooldownNcnVaultTicketCpiAccounts {
+            config: &ctx.accounts.jito_restaking_config.to_account_info(),
+            ncn: &ctx.accounts.ncn.to_account_info(),
+            vault: &ctx.accounts.vault.to_account_info(),
+            ncn_vault_ticket: &ctx.accounts.ncn_vault_ticket.to_account_info(),
+            admin: &ctx.accounts.ncn_admin.to_account_info(),
+        },
+    )
+    .invoke_signed(&[&[
+        SEED_NCN_ADMIN,
+        ctx.accounts.ncn.key().as_ref(),
+        &[ctx.bumps.ncn_admin],
+    ]])?;
+
+    JoinRequest::revoke(&ctx.accounts.join_request)?;
+
+    emit!(VaultCooledDown {
+        config: ctx.accounts.config.key(),
+        vault: ctx.accounts.vault.key(),
+    });
+
+    Ok(())
+}
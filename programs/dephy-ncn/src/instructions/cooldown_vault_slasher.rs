@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::JitoRestaking;
+
+use crate::{constants::*, error::DephyNcnError, events::VaultSlasherCooledDown, state::Config};
+
+#[derive(Accounts)]
+pub struct CooldownVaultSlasher<'info> {
+    #[account(has_one = authority @ DephyNcnError::InvalidAuthority)]
+    pub config: Account<'info, Config>,
+    /// CHECK:
+    #[account(address = config.ncn)]
+    pub ncn: UncheckedAccount<'info>,
+    /// CHECK:
+    pub vault: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [SEED_NCN_VAULT_SLASHER_TICKET, ncn.key.as_ref(), vault.key.as_ref(), config.key().as_ref()],
+        seeds::program = jito_restaking_program, bump
+    )]
+    pub ncn_vault_slasher_ticket: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = jito_restaking_program)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_NCN_ADMIN, ncn.key().as_ref()], bump)]
+    pub ncn_admin: SystemAccount<'info>,
+    pub authority: Signer<'info>,
+    pub jito_restaking_program: Program<'info, JitoRestaking>,
+}
+
+pub fn handle_cooldown_vault_slasher(ctx: Context<CooldownVaultSlasher>) -> Result<()> {
+    jito_restaking_client::instructions::CooldownNcnVaultSlasherTicketCpi::new(
+        &ctx.accounts.jito_restaking_program,
+        jito_restaking_client::instructions::CooldownNcnVaultSlasherTicketCpiAccounts {
+            config: &ctx.accounts.jito_restaking_config.to_account_info(),
+            ncn: &ctx.accounts.ncn.to_account_info(),
+            vault: &ctx.accounts.vault.to_account_info(),
+            slasher: &ctx.accounts.config.to_account_info(),
+            ncn_vault_slasher_ticket: &ctx.accounts.ncn_vault_slasher_ticket.to_account_info(),
+            admin: &ctx.accounts.ncn_admin.to_account_info(),
+        },
+    )
+    .invoke_signed(&[&[
+        SEED_NCN_ADMIN,
+        ctx.accounts.ncn.key().as_ref(),
+        &[ctx.bumps.ncn_admin],
+    ]])?;
+
+    emit!(VaultSlasherCooledDown {
+        config: ctx.accounts.config.key(),
+        vault: ctx.accounts.vault.key(),
+    });
+
+    Ok(())
+}
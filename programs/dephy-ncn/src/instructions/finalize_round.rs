@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::RoundFinalized,
+    state::{BallotBox, Config, RoundStatus},
+};
+
+/// Permissionless, makes a reached root final once its challenge period is over
+#[derive(Accounts)]
+pub struct FinalizeRound<'info> {
+    pub config: Account<'info, Config>,
+    #[account(mut, has_one = config @ DephyNcnError::ConfigMismatch,
+        seeds = [SEED_BALLOT_BOX, config.key().as_ref(), &ballot_box.round.to_le_bytes()], bump
+    )]
+    pub ballot_box: Account<'info, BallotBox>,
+}
+
+pub fn handle_finalize_round(ctx: Context<FinalizeRound>) -> Result<()> {
+    let ballot_box = &mut ctx.accounts.ballot_box;
+    require!(
+        ballot_box.status == RoundStatus::Reached,
+        DephyNcnError::InvalidRoundStatus
+    );
+    let clock = Clock::get()?;
+    require_gte!(
+        clock.slot,
+        ballot_box.challenge_end_slot,
+        DephyNcnError::ChallengePeriodNotOver
+    );
+
+    ballot_box.status = RoundStatus::Finalized;
+    ballot_box.finalized_slot = clock.slot;
+
+    emit!(RoundFinalized {
+        config: ctx.accounts.config.key(),
+        round: ballot_box.round,
+        rewards_root: ballot_box.rewards_root,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{
+    accounts::{Config as RestakingConfig, Ncn},
+    programs::JITO_RESTAKING_ID,
+};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::RoundStarted,
+    state::{BallotBox, Config, EpochSnapshot, RoundStatus},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeEpochSnapshotArgs {
+    pub round: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeEpochSnapshotArgs)]
+pub struct InitializeEpochSnapshot<'info> {
+    pub config: Account<'info, Config>,
+    /// CHECK:
+    #[account(address = config.ncn)]
+    pub ncn: UncheckedAccount<'info>,
+    #[account(init, payer = payer,
+        space = EpochSnapshot::DISCRIMINATOR.len() + EpochSnapshot::INIT_SPACE,
+        seeds = [SEED_EPOCH_SNAPSHOT, config.key().as_ref(), &args.round.to_le_bytes()], bump
+    )]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+    #[account(init, payer = payer,
+        space = BallotBox::DISCRIMINATOR.len() + BallotBox::INIT_SPACE,
+        seeds = [SEED_BALLOT_BOX, config.key().as_ref(), &args.round.to_le_bytes()], bump
+    )]
+    pub ballot_box: Account<'info, BallotBox>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_epoch_snapshot(
+    ctx: Context<InitializeEpochSnapshot>,
+    args: InitializeEpochSnapshotArgs,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let restaking_config =
+        RestakingConfig::from_bytes(&ctx.accounts.jito_restaking_config.try_borrow_data()?[8..])?;
+    let round = ctx
+        .accounts
+        .config
+        .voting_period
+        .round(clock.slot, restaking_config.epoch_length);
+    require_eq!(args.round, round, DephyNcnError::InvalidRound);
+
+    // operators and vaults added later in the round are not counted
+    let ncn = Ncn::from_bytes(&ctx.accounts.ncn.try_borrow_data()?[8..])?;
+
+    let epoch_snapshot = &mut ctx.accounts.epoch_snapshot;
+    epoch_snapshot.config = ctx.accounts.config.key();
+    epoch_snapshot.round = args.round;
+    epoch_snapshot.operator_count = ncn.operator_count;
+    epoch_snapshot.vault_count = ncn.vault_count;
+    epoch_snapshot.operators_registered = 0;
+    epoch_snapshot.total_stake = 0;
+    epoch_snapshot.payer = ctx.accounts.payer.key();
+    epoch_snapshot.operators_settled = 0;
+
+    let ballot_box = &mut ctx.accounts.ballot_box;
+    ballot_box.config = ctx.accounts.config.key();
+    ballot_box.round = args.round;
+    ballot_box.payer = ctx.accounts.payer.key();
+    ballot_box.status = RoundStatus::Voting;
+
+    emit!(RoundStarted {
+        config: ctx.accounts.config.key(),
+        round: args.round,
+        operator_count: ncn.operator_count,
+        vault_count: ncn.vault_count,
+    });
+
+    Ok(())
+}
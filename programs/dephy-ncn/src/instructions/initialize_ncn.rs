@@ -3,7 +3,7 @@ use jito_restaking_client::{programs::JITO_RESTAKING_ID, JitoRestaking};
 
 use crate::{
     constants::*,
-    state::{BallotBox, Config},
+    state::{Config, VotingPeriod},
 };
 
 #[derive(Accounts)]
@@ -19,11 +19,6 @@ pub struct InitializeNcn<'info> {
         seeds = [SEED_DEPHY_NCN, ncn.key().as_ref()], bump
     )]
     pub config: Account<'info, Config>,
-    #[account(init, payer = payer,
-        space = BallotBox::DISCRIMINATOR.len() + BallotBox::INIT_SPACE,
-        seeds = [SEED_BALLOT_BOX, config.key().as_ref()], bump
-    )]
-    pub ballot_box: Account<'info, BallotBox>,
     #[account(mut, seeds = [SEED_NCN_ADMIN, ncn.key().as_ref()], bump)]
     pub ncn_admin: SystemAccount<'info>,
     pub authority: Signer<'info>,
@@ -66,9 +61,15 @@ pub fn handle_initialize_ncn(ctx: Context<InitializeNcn>) -> Result<()> {
     let config = &mut ctx.accounts.config;
     config.ncn = ctx.accounts.ncn.key();
     config.authority = ctx.accounts.authority.key();
-
-    let ballot_box = &mut ctx.accounts.ballot_box;
-    ballot_box.config = config.key();
+    config.pending_authority = None;
+    config.allow_admin_vote = false;
+    config.consensus_threshold_bps = DEFAULT_CONSENSUS_THRESHOLD_BPS;
+    config.min_voters = DEFAULT_MIN_VOTERS;
+    config.min_participation_bps = 0;
+    config.voting_period = VotingPeriod::JitoEpoch;
+    config.ballot_retention_rounds = DEFAULT_BALLOT_RETENTION_ROUNDS;
+    config.challenge_period_slots = DEFAULT_CHALLENGE_PERIOD_SLOTS;
+    config.guardians = vec![];
 
     Ok(())
 }
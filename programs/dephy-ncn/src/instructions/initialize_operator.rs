@@ -3,7 +3,8 @@ use jito_restaking_client::JitoRestaking;
 
 use crate::{
     constants::*,
-    state::{Config, VoterState},
+    events::OperatorInitialized,
+    state::{Config, JoinKind, JoinRequest, OffenceLedger, VoterState},
 };
 
 #[derive(Accounts)]
@@ -30,13 +31,31 @@ pub struct InitializeOperator<'info> {
         seeds = [SEED_VOTER_STATE, config.key().as_ref(), operator.key().as_ref()], bump
     )]
     pub voter_state: Account<'info, VoterState>,
+    #[account(
+        init, payer = payer,
+        space = OffenceLedger::DISCRIMINATOR.len() + OffenceLedger::INIT_SPACE,
+        seeds = [SEED_OFFENCE_LEDGER, config.key().as_ref(), operator.key().as_ref()], bump
+    )]
+    pub offence_ledger: Account<'info, OffenceLedger>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
+    /// the authority, or none with an approved join request
+    pub authority: Option<Signer<'info>>,
+    pub join_request: Option<Account<'info, JoinRequest>>,
     pub jito_restaking_program: Program<'info, JitoRestaking>,
 }
 
 pub fn handle_initialize_operator(ctx: Context<InitializeOperator>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    accounts.config.check_onboarding(
+        &accounts.config.key(),
+        accounts.authority.as_ref().map(|authority| authority.key),
+        accounts.join_request.as_deref(),
+        JoinKind::Operator,
+        accounts.operator.key,
+    )?;
+
     jito_restaking_client::instructions::InitializeNcnOperatorStateCpi::new(
         &ctx.accounts.jito_restaking_program,
         jito_restaking_client::instructions::InitializeNcnOperatorStateCpiAccounts {
@@ -58,7 +77,23 @@ pub fn handle_initialize_operator(ctx: Context<InitializeOperator>) -> Result<()
     let voter_state = &mut ctx.accounts.voter_state;
     voter_state.config = ctx.accounts.config.key();
     voter_state.operator = ctx.accounts.operator.key();
-    voter_state.last_voted_epoch = 0;
+    voter_state.last_voted_round = 0;
+    voter_state.last_voted_root = [0; 32];
+    voter_state.rounds_participated = 0;
+    voter_state.rounds_missed = 0;
+    voter_state.rounds_won = 0;
+    voter_state.last_stake = 0;
+    voter_state.current_streak = 0;
+    voter_state.longest_streak = 0;
+
+    let offence_ledger = &mut ctx.accounts.offence_ledger;
+    offence_ledger.config = ctx.accounts.config.key();
+    offence_ledger.operator = ctx.accounts.operator.key();
+
+    emit!(OperatorInitialized {
+        config: ctx.accounts.config.key(),
+        operator: ctx.accounts.operator.key(),
+    });
 
     Ok(())
 }
@@ -1,42 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenInterface},
+};
+use dephy_rewards::program::DephyRewards;
 
-use crate::constants::*;
+use crate::{constants::*, error::DephyNcnError, state::Config};
 
 #[derive(Accounts)]
 pub struct InitializeRewards<'info> {
-    /// CHECK:
-    #[account(mut, seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
-    pub jito_restaking_config: UncheckedAccount<'info>,
-    pub base: Signer<'info>,
-    #[account(mut, seeds = [SEED_NCN, base.key().as_ref()], bump, seeds::program = JITO_RESTAKING_ID)]
-    pub ncn: SystemAccount<'info>,
-    #[account(init, payer = payer,
-        space = Config::DISCRIMINATOR.len() + Config::INIT_SPACE,
-        seeds = [SEED_DEPHY_NCN, ncn.key().as_ref()], bump
+    #[account(has_one = authority @ DephyNcnError::InvalidAuthority,
+        seeds = [SEED_DEPHY_NCN, config.ncn.as_ref()], bump
     )]
     pub config: Account<'info, Config>,
-    #[account(init, payer = payer,
-        space = BallotBox::DISCRIMINATOR.len() + BallotBox::INIT_SPACE,
-        seeds = [SEED_BALLOT_BOX, config.key().as_ref()], bump
-    )]
-    pub ballot_box: Account<'info, BallotBox>,
+    /// CHECK: initialized in rewards program
     #[account(mut, seeds = [SEED_REWARDS_STATE, config.key().as_ref()], bump)]
-    pub rewards_state: Account<'info, RewardsState>,
-    #[account(seeds = [SEED_REWARDS_VAULT, rewards_state.key().as_ref()], bump)]
+    pub rewards_state: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_REWARDS_VAULT, rewards_state.key().as_ref()], bump, seeds::program = rewards_program)]
     pub rewards_vault: SystemAccount<'info>,
     #[account(mint::token_program = rewards_token_program)]
     pub rewards_mint: InterfaceAccount<'info, Mint>,
     /// CHECK: checked in rewards program
     #[account(mut)]
     pub rewards_token_account: UncheckedAccount<'info>,
-    #[account(mut, seeds = [SEED_NCN_ADMIN, ncn.key().as_ref()], bump)]
-    pub ncn_admin: SystemAccount<'info>,
     pub authority: Signer<'info>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
-    /// CHECK:
-    #[account(address = JITO_RESTAKING_ID)]
-    pub jito_restaking_program: UncheckedAccount<'info>,
+    pub rewards_program: Program<'info, DephyRewards>,
     pub rewards_token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 }
+
+pub fn handle_initialize_rewards(ctx: Context<InitializeRewards>) -> Result<()> {
+    let config_key = ctx.accounts.config.key();
+
+    // config is the rewards state authority, so only published consensus roots can be claimed
+    dephy_rewards::cpi::initialize_rewards_state(CpiContext::new_with_signer(
+        ctx.accounts.rewards_program.to_account_info(),
+        dephy_rewards::cpi::accounts::InitializeRewardsState {
+            rewards_state: ctx.accounts.rewards_state.to_account_info(),
+            authority: ctx.accounts.config.to_account_info(),
+            rewards_vault: ctx.accounts.rewards_vault.to_account_info(),
+            rewards_mint: ctx.accounts.rewards_mint.to_account_info(),
+            rewards_token_account: ctx.accounts.rewards_token_account.to_account_info(),
+            payer: ctx.accounts.payer.to_account_info(),
+            rewards_token_program: ctx.accounts.rewards_token_program.to_account_info(),
+            associated_token_program: ctx.accounts.associated_token_program.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        &[
+            &[
+                SEED_REWARDS_STATE,
+                config_key.as_ref(),
+                &[ctx.bumps.rewards_state],
+            ],
+            &[
+                SEED_DEPHY_NCN,
+                ctx.accounts.config.ncn.as_ref(),
+                &[ctx.bumps.config],
+            ],
+        ],
+    ))
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use jito_restaking_client::JitoRestaking;
 
-use crate::{constants::*, state::Config};
+use crate::{
+    constants::*,
+    events::VaultInitialized,
+    state::{Config, JoinKind, JoinRequest},
+};
 
 #[derive(Accounts)]
 pub struct InitializeVault<'info> {
@@ -27,10 +31,22 @@ pub struct InitializeVault<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
+    /// the authority, or none with an approved join request
+    pub authority: Option<Signer<'info>>,
+    pub join_request: Option<Account<'info, JoinRequest>>,
     pub jito_restaking_program: Program<'info, JitoRestaking>,
 }
 
 pub fn handle_initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    accounts.config.check_onboarding(
+        &accounts.config.key(),
+        accounts.authority.as_ref().map(|authority| authority.key),
+        accounts.join_request.as_deref(),
+        JoinKind::Vault,
+        accounts.vault.key,
+    )?;
+
     jito_restaking_client::instructions::InitializeNcnVaultTicketCpi::new(
         &ctx.accounts.jito_restaking_program,
         jito_restaking_client::instructions::InitializeNcnVaultTicketCpiAccounts {
@@ -49,5 +65,10 @@ pub fn handle_initialize_vault(ctx: Context<InitializeVault>) -> Result<()> {
         &[ctx.bumps.ncn_admin],
     ]])?;
 
+    emit!(VaultInitialized {
+        config: ctx.accounts.config.key(),
+        vault: ctx.accounts.vault.key(),
+    });
+
     Ok(())
 }
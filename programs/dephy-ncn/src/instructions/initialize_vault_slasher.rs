@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::JitoRestaking;
+
+use crate::{constants::*, error::DephyNcnError, state::Config};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeVaultSlasherArgs {
+    pub max_slashable_per_epoch: u64,
+}
+
+/// Register config as a slasher of a connected vault
+#[derive(Accounts)]
+pub struct InitializeVaultSlasher<'info> {
+    #[account(has_one = authority @ DephyNcnError::InvalidAuthority,
+        seeds = [SEED_DEPHY_NCN, config.ncn.as_ref()], bump
+    )]
+    pub config: Account<'info, Config>,
+    /// CHECK:
+    #[account(mut, address = config.ncn)]
+    pub ncn: UncheckedAccount<'info>,
+    /// CHECK:
+    pub vault: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(
+        seeds = [SEED_NCN_VAULT_TICKET, ncn.key().as_ref(), vault.key().as_ref()],
+        seeds::program = jito_restaking_program, bump
+    )]
+    pub ncn_vault_ticket: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(
+        mut,
+        seeds = [SEED_NCN_VAULT_SLASHER_TICKET, ncn.key().as_ref(), vault.key().as_ref(), config.key().as_ref()],
+        seeds::program = jito_restaking_program, bump
+    )]
+    pub ncn_vault_slasher_ticket: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = jito_restaking_program)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_NCN_ADMIN, ncn.key().as_ref()], bump)]
+    pub ncn_admin: SystemAccount<'info>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+    pub jito_restaking_program: Program<'info, JitoRestaking>,
+}
+
+pub fn handle_initialize_vault_slasher(
+    ctx: Context<InitializeVaultSlasher>,
+    args: InitializeVaultSlasherArgs,
+) -> Result<()> {
+    jito_restaking_client::instructions::InitializeNcnVaultSlasherTicketCpi::new(
+        &ctx.accounts.jito_restaking_program,
+        jito_restaking_client::instructions::InitializeNcnVaultSlasherTicketCpiAccounts {
+            config: &ctx.accounts.jito_restaking_config.to_account_info(),
+            ncn: &ctx.accounts.ncn.to_account_info(),
+            vault: &ctx.accounts.vault.to_account_info(),
+            slasher: &ctx.accounts.config.to_account_info(),
+            ncn_vault_ticket: &ctx.accounts.ncn_vault_ticket.to_account_info(),
+            ncn_vault_slasher_ticket: &ctx.accounts.ncn_vault_slasher_ticket.to_account_info(),
+            admin: &ctx.accounts.ncn_admin.to_account_info(),
+            payer: &ctx.accounts.payer.to_account_info(),
+            system_program: &ctx.accounts.system_program.to_account_info(),
+        },
+        jito_restaking_client::instructions::InitializeNcnVaultSlasherTicketInstructionArgs {
+            max_slashable_per_epoch: args.max_slashable_per_epoch,
+        },
+    )
+    .invoke_signed(&[&[
+        SEED_NCN_ADMIN,
+        ctx.accounts.ncn.key().as_ref(),
+        &[ctx.bumps.ncn_admin],
+    ]])?;
+
+    Ok(())
+}
@@ -1,13 +1,61 @@
+mod accept_authority;
+mod add_rewards_mint;
+mod close_ballot_box;
+mod close_epoch_snapshot;
+mod close_operator_snapshot;
+mod cooldown_operator;
+mod cooldown_vault;
+mod cooldown_vault_slasher;
+mod finalize_round;
+mod initialize_epoch_snapshot;
 mod initialize_ncn;
-mod initialize_vault;
 mod initialize_operator;
+mod initialize_rewards;
+mod initialize_vault;
+mod initialize_vault_slasher;
+mod propose_authority;
+mod publish_rewards_root;
+mod request_join;
+mod review_join_request;
+mod revote;
+mod set_ncn_admin;
+mod settle_operator_round;
+mod snapshot_operator;
+mod snapshot_operator_vault;
+mod update_config;
+mod veto_round;
 mod vote;
-mod warmup_vault;
 mod warmup_operator;
+mod warmup_vault;
+mod warmup_vault_slasher;
 
+pub use accept_authority::*;
+pub use add_rewards_mint::*;
+pub use close_ballot_box::*;
+pub use close_epoch_snapshot::*;
+pub use close_operator_snapshot::*;
+pub use cooldown_operator::*;
+pub use cooldown_vault::*;
+pub use cooldown_vault_slasher::*;
+pub use finalize_round::*;
+pub use initialize_epoch_snapshot::*;
 pub use initialize_ncn::*;
-pub use initialize_vault::*;
 pub use initialize_operator::*;
+pub use initialize_rewards::*;
+pub use initialize_vault::*;
+pub use initialize_vault_slasher::*;
+pub use propose_authority::*;
+pub use publish_rewards_root::*;
+pub use request_join::*;
+pub use review_join_request::*;
+pub use revote::*;
+pub use set_ncn_admin::*;
+pub use settle_operator_round::*;
+pub use snapshot_operator::*;
+pub use snapshot_operator_vault::*;
+pub use update_config::*;
+pub use veto_round::*;
 pub use vote::*;
-pub use warmup_vault::*;
 pub use warmup_operator::*;
+pub use warmup_vault::*;
+pub use warmup_vault_slasher::*;
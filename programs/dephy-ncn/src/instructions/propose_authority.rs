@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::{error::DephyNcnError, state::Config};
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(mut, has_one = authority @ DephyNcnError::InvalidAuthority)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    /// CHECK: proposed authority, proposing the current one cancels a pending transfer
+    pub new_authority: UncheckedAccount<'info>,
+}
+
+pub fn handle_propose_authority(ctx: Context<ProposeAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    config.pending_authority = if new_authority == config.authority {
+        None
+    } else {
+        Some(new_authority)
+    };
+
+    Ok(())
+}
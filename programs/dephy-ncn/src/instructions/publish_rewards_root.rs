@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use dephy_rewards::{program::DephyRewards, MerkleRoot, RewardsState, UpdateMerkleRootArgs};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::RewardsRootPublished,
+    state::{BallotBox, Config},
+};
+
+#[derive(Accounts)]
+pub struct PublishRewardsRoot<'info> {
+    #[account(mut, seeds = [SEED_DEPHY_NCN, config.ncn.as_ref()], bump)]
+    pub config: Account<'info, Config>,
+    #[account(has_one = config @ DephyNcnError::ConfigMismatch,
+        seeds = [SEED_BALLOT_BOX, config.key().as_ref(), &ballot_box.round.to_le_bytes()], bump
+    )]
+    pub ballot_box: Account<'info, BallotBox>,
+    #[account(mut, seeds = [SEED_REWARDS_STATE, config.key().as_ref()], bump)]
+    pub rewards_state: Account<'info, RewardsState>,
+    /// CHECK: created by dephy-rewards
+    #[account(mut)]
+    pub merkle_root_version: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rewards_program: Program<'info, DephyRewards>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_publish_rewards_root(ctx: Context<PublishRewardsRoot>) -> Result<()> {
+    let ballot_box = &ctx.accounts.ballot_box;
+    require!(
+        ballot_box.is_finalized(),
+        DephyNcnError::ConsensusNotReached
+    );
+    // an older round must not replace a newer root
+    require_gt!(
+        ballot_box.round,
+        ctx.accounts.config.last_published_round,
+        DephyNcnError::AlreadyPublished
+    );
+
+    dephy_rewards::cpi::update_merkle_root(
+        CpiContext::new_with_signer(
+            ctx.accounts.rewards_program.to_account_info(),
+            dephy_rewards::cpi::accounts::UpdateMerkleRoot {
+                rewards_state: ctx.accounts.rewards_state.to_account_info(),
+                authority: ctx.accounts.config.to_account_info(),
+                merkle_root_version: ctx.accounts.merkle_root_version.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[&[
+                SEED_DEPHY_NCN,
+                ctx.accounts.config.ncn.as_ref(),
+                &[ctx.bumps.config],
+            ]],
+        ),
+        UpdateMerkleRootArgs {
+            merkle_root: MerkleRoot::Inplace {
+                hash: ballot_box.rewards_root,
+            },
+            // operators vote on the root only
+            total_amount: 0,
+            leaf_count: 0,
+        },
+    )?;
+
+    emit!(RewardsRootPublished {
+        config: ctx.accounts.config.key(),
+        round: ballot_box.round,
+        rewards_state: ctx.accounts.rewards_state.key(),
+        rewards_root: ballot_box.rewards_root,
+    });
+
+    let round = ballot_box.round;
+    ctx.accounts.config.last_published_round = round;
+
+    Ok(())
+}
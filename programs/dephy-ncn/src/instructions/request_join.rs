@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{accounts::Operator, programs::JITO_RESTAKING_ID};
+use jito_vault_client::{accounts::Vault, programs::JITO_VAULT_ID};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::JoinRequested,
+    state::{Config, JoinKind, JoinRequest},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RequestJoinArgs {
+    pub kind: JoinKind,
+}
+
+#[derive(Accounts)]
+pub struct RequestJoin<'info> {
+    pub config: Account<'info, Config>,
+    /// CHECK: vault or operator, checked against the kind
+    pub target: UncheckedAccount<'info>,
+    #[account(
+        init, payer = payer,
+        space = JoinRequest::DISCRIMINATOR.len() + JoinRequest::INIT_SPACE,
+        seeds = [SEED_JOIN_REQUEST, config.key().as_ref(), target.key().as_ref()], bump
+    )]
+    pub join_request: Account<'info, JoinRequest>,
+    /// admin of the vault or operator
+    pub applicant: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_request_join(ctx: Context<RequestJoin>, args: RequestJoinArgs) -> Result<()> {
+    let target = &ctx.accounts.target;
+    let admin = match args.kind {
+        JoinKind::Vault => {
+            require_keys_eq!(*target.owner, JITO_VAULT_ID, DephyNcnError::InvalidVault);
+            Vault::from_bytes(
+                target
+                    .try_borrow_data()?
+                    .get(8..)
+                    .ok_or(DephyNcnError::InvalidVault)?,
+            )?
+            .admin
+        }
+        JoinKind::Operator => {
+            require_keys_eq!(
+                *target.owner,
+                JITO_RESTAKING_ID,
+                DephyNcnError::InvalidOperator
+            );
+            Operator::from_bytes(
+                target
+                    .try_borrow_data()?
+                    .get(8..)
+                    .ok_or(DephyNcnError::InvalidOperator)?,
+            )?
+            .admin
+        }
+    };
+    require_keys_eq!(
+        admin,
+        ctx.accounts.applicant.key(),
+        DephyNcnError::InvalidApplicant
+    );
+
+    let join_request = &mut ctx.accounts.join_request;
+    join_request.config = ctx.accounts.config.key();
+    join_request.kind = args.kind;
+    join_request.target = target.key();
+    join_request.applicant = admin;
+    join_request.payer = ctx.accounts.payer.key();
+    join_request.approved = false;
+    join_request.requested_slot = Clock::get()?.slot;
+
+    emit!(JoinRequested {
+        config: join_request.config,
+        kind: args.kind,
+        target: join_request.target,
+        applicant: admin,
+    });
+
+    Ok(())
+}
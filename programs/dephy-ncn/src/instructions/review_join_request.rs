@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    error::DephyNcnError,
+    events::JoinRequestReviewed,
+    state::{Config, JoinRequest},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ReviewJoinRequestArgs {
+    /// a rejected request is closed, so the applicant can apply again.
+    /// Rejecting an approved request revokes it
+    pub approve: bool,
+}
+
+#[derive(Accounts)]
+pub struct ReviewJoinRequest<'info> {
+    #[account(has_one = authority @ DephyNcnError::InvalidAuthority)]
+    pub config: Account<'info, Config>,
+    #[account(mut, has_one = config @ DephyNcnError::ConfigMismatch, has_one = payer)]
+    pub join_request: Account<'info, JoinRequest>,
+    pub authority: Signer<'info>,
+    /// CHECK: refunded on rejection
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn handle_review_join_request(
+    ctx: Context<ReviewJoinRequest>,
+    args: ReviewJoinRequestArgs,
+) -> Result<()> {
+    let join_request = &mut ctx.accounts.join_request;
+    require!(
+        !(args.approve && join_request.approved),
+        DephyNcnError::JoinRequestReviewed
+    );
+
+    emit!(JoinRequestReviewed {
+        config: join_request.config,
+        kind: join_request.kind,
+        target: join_request.target,
+        approved: args.approve,
+    });
+
+    if args.approve {
+        join_request.approved = true;
+        Ok(())
+    } else {
+        join_request.close(ctx.accounts.payer.to_account_info())
+    }
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use super::{Vote, VoteArgs};
+use crate::{error::DephyNcnError, events::Revoted, state::RoundStatus};
+
+/// Move the operator's vote of the current round to another root, before consensus
+pub fn handle_revote(ctx: Context<Vote>, args: VoteArgs) -> Result<()> {
+    let clock = Clock::get()?;
+    let round = ctx.accounts.check_voter(&clock)?;
+
+    let voter_state = &ctx.accounts.voter_state;
+    require!(
+        voter_state.rounds_participated > 0 && voter_state.last_voted_round == round,
+        DephyNcnError::InvalidRound
+    );
+    require!(
+        ctx.accounts.ballot_box.status == RoundStatus::Voting,
+        DephyNcnError::ConsensusAlreadyReached
+    );
+    let previous_root = voter_state.last_voted_root;
+    require!(
+        previous_root != args.proposed_rewards_root,
+        DephyNcnError::AlreadyVoted
+    );
+
+    // same snapshot stake as the first vote of the round
+    let staked_amount = ctx.accounts.operator_snapshot.total_stake;
+    let ballot_box = &mut ctx.accounts.ballot_box;
+    ballot_box.withdraw(previous_root, staked_amount)?;
+    ballot_box.cast(args.proposed_rewards_root, staked_amount)?;
+
+    ctx.accounts.voter_state.last_voted_root = args.proposed_rewards_root;
+    ctx.accounts.operator_snapshot.voted_root = Some(args.proposed_rewards_root);
+
+    emit!(Revoted {
+        config: ctx.accounts.config.key(),
+        operator: ctx.accounts.operator.key(),
+        round,
+        previous_root,
+        rewards_root: args.proposed_rewards_root,
+        stake: staked_amount,
+    });
+
+    ctx.accounts.try_reach_consensus(&clock);
+
+    Ok(())
+}
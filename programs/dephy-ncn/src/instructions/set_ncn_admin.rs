@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::JitoRestaking;
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::NcnAdminSet,
+    state::{Config, NcnRole},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetNcnAdminArgs {
+    pub role: NcnRole,
+}
+
+#[derive(Accounts)]
+pub struct SetNcnAdmin<'info> {
+    #[account(has_one = authority @ DephyNcnError::InvalidAuthority)]
+    pub config: Account<'info, Config>,
+    /// CHECK:
+    #[account(mut, address = config.ncn)]
+    pub ncn: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_NCN_ADMIN, ncn.key().as_ref()], bump)]
+    pub ncn_admin: SystemAccount<'info>,
+    /// CHECK: must sign when taking the `Admin` role
+    pub new_admin: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    pub jito_restaking_program: Program<'info, JitoRestaking>,
+}
+
+pub fn handle_set_ncn_admin(ctx: Context<SetNcnAdmin>, args: SetNcnAdminArgs) -> Result<()> {
+    let ncn_admin_seeds: &[&[u8]] = &[
+        SEED_NCN_ADMIN,
+        ctx.accounts.ncn.key.as_ref(),
+        &[ctx.bumps.ncn_admin],
+    ];
+
+    // secondary roles can be handed back to the ncn admin PDA later
+    match args.role.secondary() {
+        Some(ncn_admin_role) => {
+            jito_restaking_client::instructions::NcnSetSecondaryAdminCpi::new(
+                &ctx.accounts.jito_restaking_program,
+                jito_restaking_client::instructions::NcnSetSecondaryAdminCpiAccounts {
+                    ncn: &ctx.accounts.ncn.to_account_info(),
+                    admin: &ctx.accounts.ncn_admin.to_account_info(),
+                    new_admin: &ctx.accounts.new_admin.to_account_info(),
+                },
+                jito_restaking_client::instructions::NcnSetSecondaryAdminInstructionArgs {
+                    ncn_admin_role,
+                },
+            )
+            .invoke_signed(&[ncn_admin_seeds])?;
+        }
+        None => {
+            require!(
+                ctx.accounts.new_admin.is_signer,
+                DephyNcnError::InvalidAuthority
+            );
+            jito_restaking_client::instructions::NcnSetAdminCpi::new(
+                &ctx.accounts.jito_restaking_program,
+                jito_restaking_client::instructions::NcnSetAdminCpiAccounts {
+                    ncn: &ctx.accounts.ncn.to_account_info(),
+                    old_admin: &ctx.accounts.ncn_admin.to_account_info(),
+                    new_admin: &ctx.accounts.new_admin.to_account_info(),
+                },
+            )
+            .invoke_signed(&[ncn_admin_seeds])?;
+        }
+    }
+
+    emit!(NcnAdminSet {
+        config: ctx.accounts.config.key(),
+        role: args.role,
+        new_admin: ctx.accounts.new_admin.key(),
+    });
+
+    Ok(())
+}
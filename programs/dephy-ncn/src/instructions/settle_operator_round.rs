@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{accounts::Config as RestakingConfig, programs::JITO_RESTAKING_ID};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    state::{
+        BallotBox, Config, EpochSnapshot, Offence, OffenceKind, OffenceLedger, OperatorSnapshot,
+        RoundStatus, VoterState,
+    },
+};
+
+/// Permissionless crank, updates performance and offences of an operator once a round is over
+#[derive(Accounts)]
+pub struct SettleOperatorRound<'info> {
+    pub config: Account<'info, Config>,
+    #[account(mut, seeds = [SEED_EPOCH_SNAPSHOT, config.key().as_ref(), &epoch_snapshot.round.to_le_bytes()], bump)]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+    #[account(seeds = [SEED_BALLOT_BOX, config.key().as_ref(), &epoch_snapshot.round.to_le_bytes()], bump)]
+    pub ballot_box: Account<'info, BallotBox>,
+    #[account(mut, seeds = [SEED_OPERATOR_SNAPSHOT, epoch_snapshot.key().as_ref(), operator_snapshot.operator.as_ref()], bump)]
+    pub operator_snapshot: Account<'info, OperatorSnapshot>,
+    #[account(mut, seeds = [SEED_VOTER_STATE, config.key().as_ref(), operator_snapshot.operator.as_ref()], bump)]
+    pub voter_state: Account<'info, VoterState>,
+    #[account(mut, seeds = [SEED_OFFENCE_LEDGER, config.key().as_ref(), operator_snapshot.operator.as_ref()], bump)]
+    pub offence_ledger: Account<'info, OffenceLedger>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+}
+
+pub fn handle_settle_operator_round(ctx: Context<SettleOperatorRound>) -> Result<()> {
+    let ballot_box = &ctx.accounts.ballot_box;
+    let operator_snapshot = &mut ctx.accounts.operator_snapshot;
+    let voter_state = &mut ctx.accounts.voter_state;
+    require!(
+        !operator_snapshot.settled,
+        DephyNcnError::RoundAlreadySettled
+    );
+
+    let clock = Clock::get()?;
+    let restaking_config =
+        RestakingConfig::from_bytes(&ctx.accounts.jito_restaking_config.try_borrow_data()?[8..])?;
+    let round = ctx
+        .accounts
+        .config
+        .voting_period
+        .round(clock.slot, restaking_config.epoch_length);
+    // votes are final once the round is over, and the root once its challenge period is
+    require_gt!(round, operator_snapshot.round, DephyNcnError::InvalidRound);
+    require!(
+        ballot_box.status != RoundStatus::Reached,
+        DephyNcnError::InvalidRoundStatus
+    );
+
+    operator_snapshot.settled = true;
+    ctx.accounts.epoch_snapshot.operators_settled += 1;
+
+    // operators without stake are not expected to vote
+    if operator_snapshot.total_stake == 0 {
+        return Ok(());
+    }
+
+    let offence_kind = match operator_snapshot.voted_root {
+        None => {
+            voter_state.rounds_missed += 1;
+            // settling can lag, a streak started after the missed round is kept
+            if voter_state.last_voted_round < operator_snapshot.round {
+                voter_state.current_streak = 0;
+            }
+            Some(OffenceKind::MissedRound)
+        }
+        Some(voted_root) if ballot_box.is_finalized() => {
+            if voted_root == ballot_box.rewards_root {
+                voter_state.rounds_won += 1;
+                None
+            } else {
+                Some(OffenceKind::LosingVote)
+            }
+        }
+        // no consensus in the round
+        Some(_) => None,
+    };
+
+    if let Some(kind) = offence_kind {
+        ctx.accounts.offence_ledger.record(Offence {
+            round: operator_snapshot.round,
+            kind,
+            stake: operator_snapshot.total_stake,
+        });
+    }
+
+    Ok(())
+}
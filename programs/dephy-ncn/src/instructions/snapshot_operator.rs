@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{
+    accounts::{Config as RestakingConfig, NcnOperatorState, Operator},
+    programs::JITO_RESTAKING_ID,
+};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    slot_toggle::SlotToggleExt,
+    state::{Config, EpochSnapshot, OperatorSnapshot},
+};
+
+/// Registers the operator, its stake is added vault by vault with `snapshot_operator_vault`
+#[derive(Accounts)]
+pub struct SnapshotOperator<'info> {
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [SEED_EPOCH_SNAPSHOT, config.key().as_ref(), &epoch_snapshot.round.to_le_bytes()], bump
+    )]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+    #[account(init, payer = payer,
+        space = OperatorSnapshot::DISCRIMINATOR.len() + OperatorSnapshot::INIT_SPACE,
+        seeds = [SEED_OPERATOR_SNAPSHOT, epoch_snapshot.key().as_ref(), operator.key().as_ref()], bump
+    )]
+    pub operator_snapshot: Account<'info, OperatorSnapshot>,
+    /// CHECK:
+    #[account(owner = JITO_RESTAKING_ID)]
+    pub operator: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_NCN_OPERATOR_STATE, config.ncn.as_ref(), operator.key().as_ref()], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub ncn_operator_state: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_snapshot_operator(ctx: Context<SnapshotOperator>) -> Result<()> {
+    let epoch_snapshot = &mut ctx.accounts.epoch_snapshot;
+
+    let clock = Clock::get()?;
+    let restaking_config =
+        RestakingConfig::from_bytes(&ctx.accounts.jito_restaking_config.try_borrow_data()?[8..])?;
+    let round = ctx
+        .accounts
+        .config
+        .voting_period
+        .round(clock.slot, restaking_config.epoch_length);
+    require_eq!(epoch_snapshot.round, round, DephyNcnError::InvalidRound);
+    require_gte!(
+        MAX_SNAPSHOT_VAULTS as u64,
+        epoch_snapshot.vault_count,
+        DephyNcnError::TooManyVaults
+    );
+
+    let ncn_operator_state = NcnOperatorState::from_bytes(
+        ctx.accounts
+            .ncn_operator_state
+            .try_borrow_data()?
+            .get(8..)
+            .ok_or(DephyNcnError::InvalidNcnOperatorState)?,
+    )?;
+    require!(
+        ncn_operator_state.ncn == ctx.accounts.config.ncn
+            && ncn_operator_state.operator == ctx.accounts.operator.key(),
+        DephyNcnError::InvalidNcnOperatorState
+    );
+    // operators added after the round snapshot are not counted in it
+    require_gt!(
+        epoch_snapshot.operator_count,
+        ncn_operator_state.index,
+        DephyNcnError::OperatorNotInSnapshot
+    );
+    let operator = Operator::from_bytes(&ctx.accounts.operator.try_borrow_data()?[8..])?;
+
+    // inactive operators are registered with no stake
+    let operator_active = ncn_operator_state
+        .ncn_opt_in_state
+        .is_active(clock.slot, restaking_config.epoch_length)
+        && ncn_operator_state
+            .operator_opt_in_state
+            .is_active(clock.slot, restaking_config.epoch_length);
+    let complete = !operator_active || epoch_snapshot.vault_count == 0;
+
+    let operator_snapshot = &mut ctx.accounts.operator_snapshot;
+    operator_snapshot.epoch_snapshot = epoch_snapshot.key();
+    operator_snapshot.operator = ctx.accounts.operator.key();
+    operator_snapshot.round = epoch_snapshot.round;
+    operator_snapshot.operator_fee_bps = operator.operator_fee_bps;
+    operator_snapshot.total_stake = 0;
+    operator_snapshot.voted_root = None;
+    operator_snapshot.settled = false;
+    operator_snapshot.vault_stakes = vec![];
+    operator_snapshot.payer = ctx.accounts.payer.key();
+    operator_snapshot.complete = complete;
+
+    if complete {
+        epoch_snapshot.operators_registered += 1;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{accounts::Config as RestakingConfig, programs::JITO_RESTAKING_ID};
+use jito_vault_client::{accounts::Config as VaultConfig, programs::JITO_VAULT_ID};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    stake::StakeContext,
+    state::{Config, EpochSnapshot, OperatorSnapshot},
+};
+
+/// Adds the stake of the next ncn vault, in index order, to an operator snapshot
+///
+/// remaining accounts: the `VAULT_STAKE_ACCOUNTS_LEN` accounts of that vault
+#[derive(Accounts)]
+pub struct SnapshotOperatorVault<'info> {
+    pub config: Account<'info, Config>,
+    #[account(
+        mut,
+        seeds = [SEED_EPOCH_SNAPSHOT, config.key().as_ref(), &epoch_snapshot.round.to_le_bytes()], bump
+    )]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+    #[account(
+        mut,
+        seeds = [SEED_OPERATOR_SNAPSHOT, epoch_snapshot.key().as_ref(), operator_snapshot.operator.as_ref()], bump
+    )]
+    pub operator_snapshot: Account<'info, OperatorSnapshot>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_VAULT_ID)]
+    pub jito_vault_config: UncheckedAccount<'info>,
+}
+
+pub fn handle_snapshot_operator_vault(ctx: Context<SnapshotOperatorVault>) -> Result<()> {
+    let epoch_snapshot = &mut ctx.accounts.epoch_snapshot;
+    let operator_snapshot = &mut ctx.accounts.operator_snapshot;
+    require!(
+        !operator_snapshot.complete,
+        DephyNcnError::OperatorSnapshotComplete
+    );
+
+    let clock = Clock::get()?;
+    let restaking_config =
+        RestakingConfig::from_bytes(&ctx.accounts.jito_restaking_config.try_borrow_data()?[8..])?;
+    let round = ctx
+        .accounts
+        .config
+        .voting_period
+        .round(clock.slot, restaking_config.epoch_length);
+    require_eq!(epoch_snapshot.round, round, DephyNcnError::InvalidRound);
+
+    let vault_config =
+        VaultConfig::from_bytes(&ctx.accounts.jito_vault_config.try_borrow_data()?[8..])?;
+    let stake_context = StakeContext {
+        ncn: ctx.accounts.config.ncn,
+        operator: operator_snapshot.operator,
+        slot: clock.slot,
+        restaking_epoch_length: restaking_config.epoch_length,
+        vault_epoch_length: vault_config.epoch_length,
+    };
+    let vault_stake = stake_context.load_indexed_vault_stake(
+        ctx.remaining_accounts,
+        operator_snapshot.vault_stakes.len() as u64,
+    )?;
+
+    operator_snapshot.total_stake += vault_stake.staked_amount;
+    epoch_snapshot.total_stake += vault_stake.staked_amount;
+    operator_snapshot.vault_stakes.push(vault_stake);
+
+    if operator_snapshot.vault_stakes.len() as u64 == epoch_snapshot.vault_count {
+        operator_snapshot.complete = true;
+        epoch_snapshot.operators_registered += 1;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use jito_restaking_client::{accounts::Config as RestakingConfig, programs::JITO_RESTAKING_ID};
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    state::{Config, VotingPeriod},
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateConfigArgs {
+    pub allow_admin_vote: Option<bool>,
+    pub consensus_threshold_bps: Option<u16>,
+    pub min_voters: Option<u64>,
+    pub min_participation_bps: Option<u16>,
+    /// the current round number must not go back, so the period can not be made longer
+    pub voting_period: Option<VotingPeriod>,
+    pub ballot_retention_rounds: Option<u64>,
+    pub challenge_period_slots: Option<u64>,
+    pub guardians: Option<Vec<Pubkey>>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(mut, has_one = authority @ DephyNcnError::InvalidAuthority)]
+    pub config: Account<'info, Config>,
+    pub authority: Signer<'info>,
+    /// CHECK:
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
+}
+
+pub fn handle_update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if let Some(allow_admin_vote) = args.allow_admin_vote {
+        config.allow_admin_vote = allow_admin_vote;
+    }
+
+    if let Some(consensus_threshold_bps) = args.consensus_threshold_bps {
+        // more than half, so only one root can reach consensus
+        require!(
+            consensus_threshold_bps > BPS_DENOMINATOR / 2
+                && consensus_threshold_bps <= BPS_DENOMINATOR,
+            DephyNcnError::InvalidConfig
+        );
+        config.consensus_threshold_bps = consensus_threshold_bps;
+    }
+
+    if let Some(min_voters) = args.min_voters {
+        require_gt!(min_voters, 0, DephyNcnError::InvalidConfig);
+        config.min_voters = min_voters;
+    }
+
+    if let Some(min_participation_bps) = args.min_participation_bps {
+        require_gte!(
+            BPS_DENOMINATOR,
+            min_participation_bps,
+            DephyNcnError::InvalidConfig
+        );
+        config.min_participation_bps = min_participation_bps;
+    }
+
+    if let Some(voting_period) = args.voting_period {
+        if let VotingPeriod::Slots { length } = voting_period {
+            require_gt!(length, 0, DephyNcnError::InvalidConfig);
+        }
+
+        // round numbers must not go back, ballot box and voter states are compared against them
+        let clock = Clock::get()?;
+        let restaking_config = RestakingConfig::from_bytes(
+            &ctx.accounts.jito_restaking_config.try_borrow_data()?[8..],
+        )?;
+        require_gte!(
+            voting_period.round(clock.slot, restaking_config.epoch_length),
+            config
+                .voting_period
+                .round(clock.slot, restaking_config.epoch_length),
+            DephyNcnError::InvalidConfig
+        );
+        config.voting_period = voting_period;
+    }
+
+    if let Some(ballot_retention_rounds) = args.ballot_retention_rounds {
+        config.ballot_retention_rounds = ballot_retention_rounds;
+    }
+
+    // applies to roots reached from now on
+    if let Some(challenge_period_slots) = args.challenge_period_slots {
+        config.challenge_period_slots = challenge_period_slots;
+    }
+
+    if let Some(guardians) = args.guardians {
+        require_gte!(MAX_GUARDIANS, guardians.len(), DephyNcnError::InvalidConfig);
+        config.guardians = guardians;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::RoundVetoed,
+    state::{BallotBox, Config, RoundStatus},
+};
+
+#[derive(Accounts)]
+pub struct VetoRound<'info> {
+    pub config: Account<'info, Config>,
+    #[account(mut, has_one = config @ DephyNcnError::ConfigMismatch,
+        seeds = [SEED_BALLOT_BOX, config.key().as_ref(), &ballot_box.round.to_le_bytes()], bump
+    )]
+    pub ballot_box: Account<'info, BallotBox>,
+    /// authority or a guardian
+    pub signer: Signer<'info>,
+}
+
+pub fn handle_veto_round(ctx: Context<VetoRound>) -> Result<()> {
+    require!(
+        ctx.accounts.config.can_veto(ctx.accounts.signer.key),
+        DephyNcnError::InvalidAuthority
+    );
+
+    let ballot_box = &mut ctx.accounts.ballot_box;
+    require!(
+        ballot_box.status == RoundStatus::Reached,
+        DephyNcnError::InvalidRoundStatus
+    );
+    let clock = Clock::get()?;
+    require_gt!(
+        ballot_box.challenge_end_slot,
+        clock.slot,
+        DephyNcnError::ChallengePeriodOver
+    );
+
+    ballot_box.status = RoundStatus::Vetoed;
+
+    emit!(RoundVetoed {
+        config: ctx.accounts.config.key(),
+        round: ballot_box.round,
+        rewards_root: ballot_box.rewards_root,
+        vetoed_by: ctx.accounts.signer.key(),
+    });
+
+    Ok(())
+}
@@ -1,17 +1,15 @@
 use anchor_lang::prelude::*;
 use jito_restaking_client::{
-    accounts::{Operator, OperatorVaultTicket},
+    accounts::{Config as RestakingConfig, NcnOperatorState, Operator},
     programs::JITO_RESTAKING_ID,
 };
-use jito_vault_client::{
-    accounts::{Vault, VaultOperatorDelegation},
-    programs::JITO_VAULT_ID,
-};
 
 use crate::{
     constants::*,
     error::DephyNcnError,
-    state::{BallotBox, Config, VoterState},
+    events::{ConsensusReached, Voted},
+    slot_toggle::SlotToggleExt,
+    state::{BallotBox, Config, EpochSnapshot, OperatorSnapshot, RoundStatus, VoterState},
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -22,97 +20,149 @@ pub struct VoteArgs {
 #[derive(Accounts)]
 pub struct Vote<'info> {
     pub config: Account<'info, Config>,
-    #[account(mut, seeds = [SEED_BALLOT_BOX, config.key().as_ref()], bump)]
+    #[account(mut, seeds = [SEED_BALLOT_BOX, config.key().as_ref(), &epoch_snapshot.round.to_le_bytes()], bump)]
     pub ballot_box: Account<'info, BallotBox>,
+    #[account(seeds = [SEED_EPOCH_SNAPSHOT, config.key().as_ref(), &epoch_snapshot.round.to_le_bytes()], bump)]
+    pub epoch_snapshot: Account<'info, EpochSnapshot>,
+    #[account(mut, seeds = [SEED_OPERATOR_SNAPSHOT, epoch_snapshot.key().as_ref(), operator.key().as_ref()], bump)]
+    pub operator_snapshot: Account<'info, OperatorSnapshot>,
     #[account(mut, seeds = [SEED_VOTER_STATE, config.key().as_ref(), operator.key().as_ref()], bump)]
     pub voter_state: Account<'info, VoterState>,
-    pub operator_admin: Signer<'info>,
-    /// CHECK:
-    #[account()]
-    pub vault: UncheckedAccount<'info>,
+    pub voter: Signer<'info>,
     /// CHECK:
     #[account(address = voter_state.operator)]
     pub operator: UncheckedAccount<'info>,
     /// CHECK:
-    #[account(seeds = [SEED_OPERATOR_VAULT_TICKET, operator.key().as_ref(), vault.key().as_ref()], bump, seeds::program = JITO_RESTAKING_ID)]
-    pub operator_vault_ticket: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_NCN_OPERATOR_STATE, config.ncn.as_ref(), operator.key().as_ref()], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub ncn_operator_state: UncheckedAccount<'info>,
     /// CHECK:
-    #[account(seeds = [SEED_VAULT_OPERATOR_DELEGATION, vault.key().as_ref(), operator.key().as_ref()], bump, seeds::program = JITO_VAULT_ID)]
-    pub vault_operator_delegation: UncheckedAccount<'info>,
+    #[account(seeds = [SEED_CONFIG], bump, seeds::program = JITO_RESTAKING_ID)]
+    pub jito_restaking_config: UncheckedAccount<'info>,
 }
 
-pub fn handle_vote(ctx: Context<Vote>, args: VoteArgs) -> Result<()> {
-    let ballot_box = &mut ctx.accounts.ballot_box;
-    let voter_state = &mut ctx.accounts.voter_state;
-
-    let clock = Clock::get()?;
-    require!(
-        clock.epoch > voter_state.last_voted_epoch,
-        DephyNcnError::InvalidEpoch
-    );
+impl Vote<'_> {
+    /// Current round, once the voter may vote in it for the operator
+    pub(crate) fn check_voter(&self, clock: &Clock) -> Result<u64> {
+        let restaking_config =
+            RestakingConfig::from_bytes(&self.jito_restaking_config.try_borrow_data()?[8..])?;
+        let round = self
+            .config
+            .voting_period
+            .round(clock.slot, restaking_config.epoch_length);
 
-    // TODO: check more
-    // Vault-Operator
-    {
-        let operator = Operator::from_bytes(&ctx.accounts.operator.try_borrow_data()?[8..])?;
-        require_keys_eq!(
-            operator.admin,
-            ctx.accounts.operator_admin.key(),
-            DephyNcnError::InvalidOperator
+        let epoch_snapshot = &self.epoch_snapshot;
+        require_eq!(epoch_snapshot.round, round, DephyNcnError::InvalidRound);
+        require!(
+            epoch_snapshot.is_complete(),
+            DephyNcnError::EpochSnapshotIncomplete
         );
-    }
 
-    {
-        let operator_vault_ticket = OperatorVaultTicket::from_bytes(
-            &ctx.accounts.operator_vault_ticket.try_borrow_data()?[8..],
-        )?;
-        require_keys_eq!(
-            operator_vault_ticket.operator,
-            ctx.accounts.operator.key(),
-            DephyNcnError::InvalidOperatorVaultTicket
-        );
-    }
-
-    let vault_operator_delegation = VaultOperatorDelegation::from_bytes(
-        &ctx.accounts.vault_operator_delegation.try_borrow_data()?[8..],
-    )?;
-    require!(
-        vault_operator_delegation.delegation_state.staked_amount > 0,
-        DephyNcnError::NoDelegation
-    );
+        // NCN-Operator
+        {
+            let ncn_operator_state = NcnOperatorState::from_bytes(
+                self.ncn_operator_state
+                    .try_borrow_data()?
+                    .get(8..)
+                    .ok_or(DephyNcnError::InvalidNcnOperatorState)?,
+            )?;
+            require!(
+                ncn_operator_state.ncn == self.config.ncn
+                    && ncn_operator_state.operator == self.operator.key(),
+                DephyNcnError::InvalidNcnOperatorState
+            );
+            require!(
+                ncn_operator_state
+                    .ncn_opt_in_state
+                    .is_active(clock.slot, restaking_config.epoch_length)
+                    && ncn_operator_state
+                        .operator_opt_in_state
+                        .is_active(clock.slot, restaking_config.epoch_length),
+                DephyNcnError::OperatorNotActive
+            );
+        }
 
-    // first voter for this epoch
-    // TODO: slash if consensus not reached?
-    if clock.epoch > ballot_box.epoch {
-        ballot_box.epoch = clock.epoch;
-        ballot_box.approved_votes = 0;
-        ballot_box.total_votes = 0;
-        ballot_box.operators_voted = 0;
-        ballot_box.proposed_rewards_root = args.proposed_rewards_root;
-    }
+        // Operator-Voter
+        {
+            let operator = Operator::from_bytes(&self.operator.try_borrow_data()?[8..])?;
+            let voter = self.voter.key();
+            require!(
+                operator.voter == voter
+                    || (self.config.allow_admin_vote && operator.admin == voter),
+                DephyNcnError::InvalidVoter
+            );
+        }
 
-    require_eq!(clock.epoch, ballot_box.epoch, DephyNcnError::InvalidEpoch);
+        // weighted by the stake frozen in the snapshot
+        require!(
+            self.operator_snapshot.total_stake > 0,
+            DephyNcnError::NoDelegation
+        );
 
-    let proposed_rewards_root = ballot_box.proposed_rewards_root;
-    ballot_box.operators_voted += 1;
-    if args.proposed_rewards_root == proposed_rewards_root {
-        ballot_box.approved_votes += vault_operator_delegation.delegation_state.staked_amount;
+        Ok(round)
     }
-    ballot_box.total_votes += vault_operator_delegation.delegation_state.staked_amount;
 
-    voter_state.last_voted_epoch = clock.epoch;
+    /// Start the challenge period when a root first reaches consensus
+    pub(crate) fn try_reach_consensus(&mut self, clock: &Clock) {
+        let ballot_box = &mut self.ballot_box;
+        if ballot_box.status != RoundStatus::Voting {
+            return;
+        }
 
-    // when consensus first reached
-    if ballot_box.epoch > ballot_box.last_consensus_epoch {
-        let vault = Vault::from_bytes(&ctx.accounts.vault.try_borrow_data()?[8..])?;
-        let consensus_reached = ballot_box.approved_votes >= vault.vrt_supply * 2 / 3;
-        if consensus_reached {
+        let config = &self.config;
+        let total_stake = self.epoch_snapshot.total_stake;
+        // participation may be reached by a vote on another root
+        let consensus = ballot_box
+            .ballots
+            .iter()
+            .find(|b| config.is_consensus(b, ballot_box.total_votes, total_stake))
+            .map(|b| (b.rewards_root, b.stake));
+        if let Some((rewards_root, stake)) = consensus {
             msg!("Consensus reached");
 
-            ballot_box.last_consensus_epoch = ballot_box.epoch;
-            ballot_box.rewards_root = proposed_rewards_root;
+            ballot_box.rewards_root = rewards_root;
+            ballot_box.status = RoundStatus::Reached;
+            ballot_box.reached_slot = clock.slot;
+            ballot_box.challenge_end_slot =
+                clock.slot.saturating_add(config.challenge_period_slots);
+
+            emit!(ConsensusReached {
+                config: config.key(),
+                round: ballot_box.round,
+                rewards_root,
+                stake,
+                total_stake,
+            });
         }
     }
+}
+
+pub fn handle_vote(ctx: Context<Vote>, args: VoteArgs) -> Result<()> {
+    let clock = Clock::get()?;
+    let round = ctx.accounts.check_voter(&clock)?;
+    require!(
+        round > ctx.accounts.voter_state.last_voted_round,
+        DephyNcnError::InvalidRound
+    );
+
+    let staked_amount = ctx.accounts.operator_snapshot.total_stake;
+    ctx.accounts
+        .ballot_box
+        .cast(args.proposed_rewards_root, staked_amount)?;
+
+    ctx.accounts
+        .voter_state
+        .record_vote(round, args.proposed_rewards_root, staked_amount);
+    ctx.accounts.operator_snapshot.voted_root = Some(args.proposed_rewards_root);
+
+    emit!(Voted {
+        config: ctx.accounts.config.key(),
+        operator: ctx.accounts.operator.key(),
+        round,
+        rewards_root: args.proposed_rewards_root,
+        stake: staked_amount,
+    });
+
+    ctx.accounts.try_reach_consensus(&clock);
 
     Ok(())
 }
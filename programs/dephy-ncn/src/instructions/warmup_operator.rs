@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 use jito_restaking_client::JitoRestaking;
 
-use crate::{constants::*, state::Config};
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::OperatorWarmedUp,
+    state::{Config, JoinKind, JoinRequest},
+};
 
 #[derive(Accounts)]
 pub struct WarmupOperator<'info> {
@@ -21,10 +26,27 @@ pub struct WarmupOperator<'info> {
     /// CHECK:
     #[account(mut, seeds = [SEED_NCN_OPERATOR_STATE, ncn.key.as_ref(), operator.key.as_ref()], seeds::program = jito_restaking_program, bump)]
     pub ncn_operator_state: UncheckedAccount<'info>,
+    /// the authority, or none with an approved join request
+    pub authority: Option<Signer<'info>>,
+    /// closed once used, so it can not onboard the target again
+    #[account(mut)]
+    pub join_request: Option<Account<'info, JoinRequest>>,
+    /// CHECK: refunded when the join request is closed
+    #[account(mut)]
+    pub join_request_payer: Option<UncheckedAccount<'info>>,
     pub jito_restaking_program: Program<'info, JitoRestaking>,
 }
 
 pub fn handle_warmup_operator(ctx: Context<WarmupOperator>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let join_request_used = accounts.config.check_onboarding(
+        &accounts.config.key(),
+        accounts.authority.as_ref().map(|authority| authority.key),
+        accounts.join_request.as_deref(),
+        JoinKind::Operator,
+        accounts.operator.key,
+    )?;
+
     jito_restaking_client::instructions::NcnWarmupOperatorCpi::new(
         &ctx.accounts.jito_restaking_program,
         jito_restaking_client::instructions::NcnWarmupOperatorCpiAccounts {
@@ -41,5 +63,22 @@ pub fn handle_warmup_operator(ctx: Context<WarmupOperator>) -> Result<()> {
         &[ctx.bumps.ncn_admin],
     ]])?;
 
+    if join_request_used {
+        if let Some(join_request) = &ctx.accounts.join_request {
+            let payer = ctx
+                .accounts
+                .join_request_payer
+                .as_ref()
+                .filter(|payer| payer.key() == join_request.payer)
+                .ok_or(DephyNcnError::JoinRequestPayerMismatch)?;
+            join_request.close(payer.to_account_info())?;
+        }
+    }
+
+    emit!(OperatorWarmedUp {
+        config: ctx.accounts.config.key(),
+        operator: ctx.accounts.operator.key(),
+    });
+
     Ok(())
 }
@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 use jito_restaking_client::JitoRestaking;
 
-use crate::{constants::*, state::Config};
+use crate::{
+    constants::*,
+    error::DephyNcnError,
+    events::VaultWarmedUp,
+    state::{Config, JoinKind, JoinRequest},
+};
 
 #[derive(Accounts)]
 pub struct WarmupVault<'info> {
@@ -24,10 +29,27 @@ pub struct WarmupVault<'info> {
     pub jito_restaking_config: UncheckedAccount<'info>,
     #[account(seeds = [SEED_NCN_ADMIN, ncn.key().as_ref()], bump)]
     pub ncn_admin: SystemAccount<'info>,
+    /// the authority, or none with an approved join request
+    pub authority: Option<Signer<'info>>,
+    /// closed once used, so it can not onboard the target again
+    #[account(mut)]
+    pub join_request: Option<Account<'info, JoinRequest>>,
+    /// CHECK: refunded when the join request is closed
+    #[account(mut)]
+    pub join_request_payer: Option<UncheckedAccount<'info>>,
     pub jito_restaking_program: Program<'info, JitoRestaking>,
 }
 
 pub fn handle_warmup_vault(ctx: Context<WarmupVault>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let join_request_used = accounts.config.check_onboarding(
+        &accounts.config.key(),
+        accounts.authority.as_ref().map(|authority| authority.key),
+        accounts.join_request.as_deref(),
+        JoinKind::Vault,
+        accounts.vault.key,
+    )?;
+
     jito_restaking_client::instructions::WarmupNcnVaultTicketCpi::new(
         &ctx.accounts.jito_restaking_program,
         jito_restaking_client::instructions::WarmupNcnVaultTicketCpiAccounts {
@@ -44,5 +66,22 @@ pub fn handle_warmup_vault(ctx: Context<WarmupVault>) -> Result<()> {
         &[ctx.bumps.ncn_admin],
     ]])?;
 
+    if join_request_used {
+        if let Some(join_request) = &ctx.accounts.join_request {
+            let payer = ctx
+                .accounts
+                .join_request_payer
+                .as_ref()
+                .filter(|payer| payer.key() == join_request.payer)
+                .ok_or(DephyNcnError::JoinRequestPayerMismatch)?;
+            join_request.close(payer.to_account_info())?;
+        }
+    }
+
+    emit!(VaultWarmedUp {
+        config: ctx.accounts.config.key(),
+        vault: ctx.accounts.vault.key(),
+    });
+
     Ok(())
 }
@@ -2,7 +2,10 @@
 
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
+pub mod slot_toggle;
+pub mod stake;
 pub mod state;
 
 use anchor_lang::prelude::*;
@@ -27,6 +30,36 @@ pub mod dephy_ncn {
         handle_warmup_vault(ctx)
     }
 
+    pub fn cooldown_vault(ctx: Context<CooldownVault>) -> Result<()> {
+        handle_cooldown_vault(ctx)
+    }
+
+    pub fn initialize_vault_slasher(
+        ctx: Context<InitializeVaultSlasher>,
+        args: InitializeVaultSlasherArgs,
+    ) -> Result<()> {
+        handle_initialize_vault_slasher(ctx, args)
+    }
+
+    pub fn warmup_vault_slasher(ctx: Context<WarmupVaultSlasher>) -> Result<()> {
+        handle_warmup_vault_slasher(ctx)
+    }
+
+    pub fn cooldown_vault_slasher(ctx: Context<CooldownVaultSlasher>) -> Result<()> {
+        handle_cooldown_vault_slasher(ctx)
+    }
+
+    pub fn request_join(ctx: Context<RequestJoin>, args: RequestJoinArgs) -> Result<()> {
+        handle_request_join(ctx, args)
+    }
+
+    pub fn review_join_request(
+        ctx: Context<ReviewJoinRequest>,
+        args: ReviewJoinRequestArgs,
+    ) -> Result<()> {
+        handle_review_join_request(ctx, args)
+    }
+
     pub fn initialize_operator(ctx: Context<InitializeOperator>) -> Result<()> {
         handle_initialize_operator(ctx)
     }
@@ -35,7 +68,82 @@ pub mod dephy_ncn {
         handle_warmup_operator(ctx)
     }
 
+    pub fn cooldown_operator(ctx: Context<CooldownOperator>) -> Result<()> {
+        handle_cooldown_operator(ctx)
+    }
+
+    pub fn initialize_epoch_snapshot(
+        ctx: Context<InitializeEpochSnapshot>,
+        args: InitializeEpochSnapshotArgs,
+    ) -> Result<()> {
+        handle_initialize_epoch_snapshot(ctx, args)
+    }
+
+    pub fn snapshot_operator(ctx: Context<SnapshotOperator>) -> Result<()> {
+        handle_snapshot_operator(ctx)
+    }
+
+    pub fn snapshot_operator_vault(ctx: Context<SnapshotOperatorVault>) -> Result<()> {
+        handle_snapshot_operator_vault(ctx)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+        handle_update_config(ctx, args)
+    }
+
+    pub fn set_ncn_admin(ctx: Context<SetNcnAdmin>, args: SetNcnAdminArgs) -> Result<()> {
+        handle_set_ncn_admin(ctx, args)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>) -> Result<()> {
+        handle_propose_authority(ctx)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        handle_accept_authority(ctx)
+    }
+
     pub fn vote(ctx: Context<Vote>, args: VoteArgs) -> Result<()> {
         handle_vote(ctx, args)
     }
+
+    pub fn revote(ctx: Context<Vote>, args: VoteArgs) -> Result<()> {
+        handle_revote(ctx, args)
+    }
+
+    pub fn settle_operator_round(ctx: Context<SettleOperatorRound>) -> Result<()> {
+        handle_settle_operator_round(ctx)
+    }
+
+    pub fn veto_round(ctx: Context<VetoRound>) -> Result<()> {
+        handle_veto_round(ctx)
+    }
+
+    pub fn finalize_round(ctx: Context<FinalizeRound>) -> Result<()> {
+        handle_finalize_round(ctx)
+    }
+
+    pub fn initialize_rewards(ctx: Context<InitializeRewards>) -> Result<()> {
+        handle_initialize_rewards(ctx)
+    }
+
+    pub fn add_rewards_mint(ctx: Context<AddRewardsMint>) -> Result<()> {
+        handle_add_rewards_mint(ctx)
+    }
+
+    pub fn publish_rewards_root(ctx: Context<PublishRewardsRoot>) -> Result<()> {
+        handle_publish_rewards_root(ctx)
+    }
+
+    pub fn close_ballot_box(ctx: Context<CloseBallotBox>) -> Result<()> {
+        handle_close_ballot_box(ctx)
+    }
+
+    pub fn close_epoch_snapshot(ctx: Context<CloseEpochSnapshot>) -> Result<()> {
+        handle_close_epoch_snapshot(ctx)
+    }
+
+    pub fn close_operator_snapshot(ctx: Context<CloseOperatorSnapshot>) -> Result<()> {
+        handle_close_operator_snapshot(ctx)
+    }
 }
@@ -0,0 +1,50 @@
+use std::cmp::Ordering;
+
+/// State of a jito `SlotToggle`, same transitions as jito-restaking-core
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlotToggleState {
+    Inactive,
+    WarmingUp,
+    Active,
+    CoolingDown,
+}
+
+impl SlotToggleState {
+    pub fn from_slots(slot_added: u64, slot_removed: u64, slot: u64, epoch_length: u64) -> Self {
+        let current_epoch = slot / epoch_length;
+        match slot_added.cmp(&slot_removed) {
+            // warmed up once the epoch after slot_added has passed
+            Ordering::Greater if current_epoch > slot_added / epoch_length + 1 => Self::Active,
+            Ordering::Greater => Self::WarmingUp,
+            // cooling down until the epoch after slot_removed has passed
+            Ordering::Less if current_epoch > slot_removed / epoch_length + 1 => Self::Inactive,
+            Ordering::Less => Self::CoolingDown,
+            Ordering::Equal => Self::Inactive,
+        }
+    }
+
+    /// Same as jito `SlotToggle::is_active`, a toggle in cooldown still counts as active
+    pub fn is_active(&self) -> bool {
+        matches!(self, Self::Active | Self::CoolingDown)
+    }
+}
+
+pub trait SlotToggleExt {
+    fn state(&self, slot: u64, epoch_length: u64) -> SlotToggleState;
+
+    fn is_active(&self, slot: u64, epoch_length: u64) -> bool {
+        self.state(slot, epoch_length).is_active()
+    }
+}
+
+impl SlotToggleExt for jito_restaking_client::types::SlotToggle {
+    fn state(&self, slot: u64, epoch_length: u64) -> SlotToggleState {
+        SlotToggleState::from_slots(self.slot_added, self.slot_removed, slot, epoch_length)
+    }
+}
+
+impl SlotToggleExt for jito_vault_client::types::SlotToggle {
+    fn state(&self, slot: u64, epoch_length: u64) -> SlotToggleState {
+        SlotToggleState::from_slots(self.slot_added, self.slot_removed, slot, epoch_length)
+    }
+}
@@ -0,0 +1,185 @@
+use anchor_lang::prelude::*;
+use jito_restaking_client::{
+    accounts::{NcnVaultTicket, OperatorVaultTicket},
+    programs::JITO_RESTAKING_ID,
+};
+use jito_vault_client::{
+    accounts::{VaultNcnTicket, VaultOperatorDelegation},
+    programs::JITO_VAULT_ID,
+};
+
+use crate::{constants::*, error::DephyNcnError, slot_toggle::SlotToggleExt, state::VaultStake};
+
+/// Accounts of a vault passed in remaining accounts:
+/// vault, operator_vault_ticket, vault_operator_delegation, ncn_vault_ticket, vault_ncn_ticket
+pub const VAULT_STAKE_ACCOUNTS_LEN: usize = 5;
+
+pub struct StakeContext {
+    pub ncn: Pubkey,
+    pub operator: Pubkey,
+    pub slot: u64,
+    pub restaking_epoch_length: u64,
+    pub vault_epoch_length: u64,
+}
+
+fn check_owner(info: &AccountInfo, program_id: &Pubkey, error: DephyNcnError) -> Result<()> {
+    if info.owner != program_id || info.data_len() <= 8 {
+        return Err(error.into());
+    }
+    Ok(())
+}
+
+fn check_pda(
+    info: &AccountInfo,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+    error: DephyNcnError,
+) -> Result<()> {
+    let address = Pubkey::create_program_address(seeds, program_id).map_err(|_| error)?;
+    require_keys_eq!(address, info.key(), error);
+    Ok(())
+}
+
+/// For accounts that may not be initialized yet, check the address only
+fn find_pda(
+    info: &AccountInfo,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+    error: DephyNcnError,
+) -> Result<()> {
+    let (address, _) = Pubkey::find_program_address(seeds, program_id);
+    require_keys_eq!(address, info.key(), error);
+    Ok(())
+}
+
+impl StakeContext {
+    /// Stake of the operator in the ncn vault at `index`, zero when the vault is not active
+    /// for the ncn or the operator has no active delegation from it
+    pub fn load_indexed_vault_stake(
+        &self,
+        accounts: &[AccountInfo],
+        index: u64,
+    ) -> Result<VaultStake> {
+        let [vault_info, operator_vault_ticket_info, vault_operator_delegation_info, ncn_vault_ticket_info, vault_ncn_ticket_info] =
+            accounts
+        else {
+            return err!(DephyNcnError::InvalidRemainingAccounts);
+        };
+        let vault_key = vault_info.key();
+
+        check_owner(vault_info, &JITO_VAULT_ID, DephyNcnError::InvalidVault)?;
+
+        // NCN-Vault, must exist for every index
+        check_owner(
+            ncn_vault_ticket_info,
+            &JITO_RESTAKING_ID,
+            DephyNcnError::InvalidNcnVaultTicket,
+        )?;
+        let ncn_vault_ticket =
+            NcnVaultTicket::from_bytes(&ncn_vault_ticket_info.try_borrow_data()?[8..])?;
+        check_pda(
+            ncn_vault_ticket_info,
+            &[
+                SEED_NCN_VAULT_TICKET,
+                self.ncn.as_ref(),
+                vault_key.as_ref(),
+                &[ncn_vault_ticket.bump],
+            ],
+            &JITO_RESTAKING_ID,
+            DephyNcnError::InvalidNcnVaultTicket,
+        )?;
+        require_eq!(
+            ncn_vault_ticket.index,
+            index,
+            DephyNcnError::InvalidVaultIndex
+        );
+        // a vault cooling down still counts, its stake stays slashable until the cooldown ends
+        if !ncn_vault_ticket
+            .state
+            .is_active(self.slot, self.restaking_epoch_length)
+        {
+            return Ok(VaultStake {
+                vault: vault_key,
+                staked_amount: 0,
+            });
+        }
+
+        // the other sides may not be connected yet
+        find_pda(
+            vault_ncn_ticket_info,
+            &[SEED_VAULT_NCN_TICKET, vault_key.as_ref(), self.ncn.as_ref()],
+            &JITO_VAULT_ID,
+            DephyNcnError::InvalidVaultNcnTicket,
+        )?;
+        find_pda(
+            operator_vault_ticket_info,
+            &[
+                SEED_OPERATOR_VAULT_TICKET,
+                self.operator.as_ref(),
+                vault_key.as_ref(),
+            ],
+            &JITO_RESTAKING_ID,
+            DephyNcnError::InvalidOperatorVaultTicket,
+        )?;
+        find_pda(
+            vault_operator_delegation_info,
+            &[
+                SEED_VAULT_OPERATOR_DELEGATION,
+                vault_key.as_ref(),
+                self.operator.as_ref(),
+            ],
+            &JITO_VAULT_ID,
+            DephyNcnError::InvalidVaultOperatorDelegation,
+        )?;
+        if vault_ncn_ticket_info.data_is_empty()
+            || operator_vault_ticket_info.data_is_empty()
+            || vault_operator_delegation_info.data_is_empty()
+        {
+            return Ok(VaultStake {
+                vault: vault_key,
+                staked_amount: 0,
+            });
+        }
+
+        check_owner(
+            vault_ncn_ticket_info,
+            &JITO_VAULT_ID,
+            DephyNcnError::InvalidVaultNcnTicket,
+        )?;
+        let vault_ncn_ticket =
+            VaultNcnTicket::from_bytes(&vault_ncn_ticket_info.try_borrow_data()?[8..])?;
+        check_owner(
+            operator_vault_ticket_info,
+            &JITO_RESTAKING_ID,
+            DephyNcnError::InvalidOperatorVaultTicket,
+        )?;
+        let operator_vault_ticket =
+            OperatorVaultTicket::from_bytes(&operator_vault_ticket_info.try_borrow_data()?[8..])?;
+        if !vault_ncn_ticket
+            .state
+            .is_active(self.slot, self.vault_epoch_length)
+            || !operator_vault_ticket
+                .state
+                .is_active(self.slot, self.restaking_epoch_length)
+        {
+            return Ok(VaultStake {
+                vault: vault_key,
+                staked_amount: 0,
+            });
+        }
+
+        check_owner(
+            vault_operator_delegation_info,
+            &JITO_VAULT_ID,
+            DephyNcnError::InvalidVaultOperatorDelegation,
+        )?;
+        let vault_operator_delegation = VaultOperatorDelegation::from_bytes(
+            &vault_operator_delegation_info.try_borrow_data()?[8..],
+        )?;
+
+        Ok(VaultStake {
+            vault: vault_key,
+            staked_amount: vault_operator_delegation.delegation_state.staked_amount,
+        })
+    }
+}
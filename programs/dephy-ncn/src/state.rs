@@ -1,35 +1,431 @@
 use anchor_lang::prelude::*;
+use jito_restaking_client::types::NcnAdminRole;
 
+use crate::{
+    constants::{BPS_DENOMINATOR, MAX_BALLOTS, MAX_GUARDIANS, MAX_OFFENCES, MAX_SNAPSHOT_VAULTS},
+    error::DephyNcnError,
+};
 
 #[account]
 #[derive(InitSpace)]
 pub struct Config {
     pub ncn: Pubkey,
     pub authority: Pubkey,
+    /// proposed authority, takes over once it accepts
+    pub pending_authority: Option<Pubkey>,
+    /// also accept votes signed by the operator admin, not only the operator voter
+    pub allow_admin_vote: bool,
+    /// share of snapshot stake a rewards root needs
+    pub consensus_threshold_bps: u16,
+    /// operators that must have voted for a rewards root
+    pub min_voters: u64,
+    /// share of snapshot stake that must have voted, on any root
+    pub min_participation_bps: u16,
+    pub voting_period: VotingPeriod,
+    /// rounds a ballot box is kept before it can be closed
+    pub ballot_retention_rounds: u64,
+    /// round of the rewards root last published to dephy-rewards
+    pub last_published_round: u64,
+    /// slots a reached root can be vetoed before it is final
+    pub challenge_period_slots: u64,
+    /// may veto reached roots, besides the authority
+    #[max_len(MAX_GUARDIANS)]
+    pub guardians: Vec<Pubkey>,
 }
 
+/// Length of a voting round, rounds are numbered from slot 0
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum VotingPeriod {
+    /// same as the jito restaking `Config.epoch_length`
+    JitoEpoch,
+    Slots {
+        length: u64,
+    },
+}
+
+impl VotingPeriod {
+    pub fn round(&self, slot: u64, jito_epoch_length: u64) -> u64 {
+        match self {
+            Self::JitoEpoch => slot / jito_epoch_length,
+            Self::Slots { length } => slot / length,
+        }
+    }
+}
+
+impl Config {
+    /// Ballot box and snapshots of `round` are kept until `ballot_retention_rounds` later rounds
+    pub fn is_retained(&self, round: u64, current_round: u64) -> bool {
+        current_round <= round.saturating_add(self.ballot_retention_rounds)
+    }
+
+    pub fn can_veto(&self, signer: &Pubkey) -> bool {
+        self.authority == *signer || self.guardians.contains(signer)
+    }
+
+    /// Onboarding a vault or operator needs the authority, or a join request it approved.
+    /// Returns whether the join request was used
+    pub fn check_onboarding(
+        &self,
+        config: &Pubkey,
+        authority: Option<&Pubkey>,
+        join_request: Option<&JoinRequest>,
+        kind: JoinKind,
+        target: &Pubkey,
+    ) -> Result<bool> {
+        if authority == Some(&self.authority) {
+            return Ok(false);
+        }
+
+        match join_request {
+            Some(join_request)
+                if join_request.approved
+                    && join_request.config == *config
+                    && join_request.kind == kind
+                    && join_request.target == *target =>
+            {
+                Ok(true)
+            }
+            _ => Err(DephyNcnError::OnboardingNotAllowed.into()),
+        }
+    }
+
+    pub fn is_consensus(&self, ballot: &Ballot, total_votes: u64, total_stake: u64) -> bool {
+        let total_stake = total_stake as u128;
+        ballot.operators_voted >= self.min_voters
+            && ballot.stake as u128 * BPS_DENOMINATOR as u128
+                >= total_stake * self.consensus_threshold_bps as u128
+            && total_votes as u128 * BPS_DENOMINATOR as u128
+                >= total_stake * self.min_participation_bps as u128
+    }
+}
 
-// TODO: vote window not bind to epoch
+/// Stake tally of one proposed rewards root
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Ballot {
+    pub rewards_root: [u8; 32],
+    pub operators_voted: u64,
+    pub stake: u64,
+}
+
+/// Votes of one round, created with the epoch snapshot of the round
+///
+/// dephy-rewards reads the fields up to `finalized_slot` as `NcnBallotBoxHeader`, keep their order
 #[account]
 #[derive(InitSpace)]
 pub struct BallotBox {
     pub config: Pubkey,
-    pub epoch: u64,
-    pub last_consensus_epoch: u64,
+    pub round: u64,
+    /// receives the rent when closed
+    pub payer: Pubkey,
     pub operators_voted: u64,
-    pub approved_votes: u64,
     pub total_votes: u64,
+    /// winning root, zero until consensus
     pub rewards_root: [u8; 32],
-    pub proposed_rewards_root: [u8; 32],
+    /// slot the root became final at, zero until then
+    pub finalized_slot: u64,
+    pub status: RoundStatus,
+    /// slot consensus was reached at, zero until then
+    pub reached_slot: u64,
+    /// the root can be vetoed until this slot
+    pub challenge_end_slot: u64,
+    /// one tally per distinct proposed root
+    #[max_len(MAX_BALLOTS)]
+    pub ballots: Vec<Ballot>,
 }
 
+/// Voting -> Reached -> Finalized or Vetoed
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum RoundStatus {
+    Voting,
+    /// a root reached consensus, in its challenge period
+    Reached,
+    Finalized,
+    Vetoed,
+}
+
+impl BallotBox {
+    pub fn is_finalized(&self) -> bool {
+        self.status == RoundStatus::Finalized
+    }
+
+    /// Add stake to the tally of `rewards_root`
+    pub fn cast(&mut self, rewards_root: [u8; 32], stake: u64) -> Result<()> {
+        let index = match self
+            .ballots
+            .iter()
+            .position(|b| b.rewards_root == rewards_root)
+        {
+            Some(index) => index,
+            None => {
+                require_gt!(
+                    MAX_BALLOTS,
+                    self.ballots.len(),
+                    DephyNcnError::TooManyBallots
+                );
+                self.ballots.push(Ballot {
+                    rewards_root,
+                    operators_voted: 0,
+                    stake: 0,
+                });
+                self.ballots.len() - 1
+            }
+        };
+
+        self.operators_voted += 1;
+        self.total_votes += stake;
+
+        let ballot = &mut self.ballots[index];
+        ballot.operators_voted += 1;
+        ballot.stake += stake;
+
+        Ok(())
+    }
+
+    /// Remove stake from the tally of `rewards_root`, dropping the tally once empty
+    pub fn withdraw(&mut self, rewards_root: [u8; 32], stake: u64) -> Result<()> {
+        let index = self
+            .ballots
+            .iter()
+            .position(|b| b.rewards_root == rewards_root)
+            .ok_or(DephyNcnError::EmptyProposedRoot)?;
+
+        self.operators_voted -= 1;
+        self.total_votes -= stake;
+
+        let ballot = &mut self.ballots[index];
+        ballot.operators_voted -= 1;
+        ballot.stake -= stake;
+        if ballot.operators_voted == 0 {
+            self.ballots.remove(index);
+        }
+
+        Ok(())
+    }
+}
 
 #[account]
 #[derive(InitSpace)]
 pub struct VoterState {
     pub config: Pubkey,
     pub operator: Pubkey,
-    pub operator_vault_ticket: Pubkey,
-    pub vault_operator_delegation: Pubkey,
-    pub last_voted_epoch: u64,
+    pub last_voted_round: u64,
+    /// root voted in `last_voted_round`, moved by `revote`
+    pub last_voted_root: [u8; 32],
+    pub rounds_participated: u64,
+    /// rounds with snapshot stake but no vote, counted when settled
+    pub rounds_missed: u64,
+    /// rounds voted for the finalized root, counted when settled
+    pub rounds_won: u64,
+    /// snapshot stake of the last vote
+    pub last_stake: u64,
+    /// consecutive rounds voted
+    pub current_streak: u64,
+    pub longest_streak: u64,
+}
+
+impl VoterState {
+    pub fn record_vote(&mut self, round: u64, rewards_root: [u8; 32], stake: u64) {
+        self.current_streak = if self.rounds_participated > 0 && round == self.last_voted_round + 1
+        {
+            self.current_streak + 1
+        } else {
+            1
+        };
+        self.longest_streak = self.longest_streak.max(self.current_streak);
+        self.rounds_participated += 1;
+        self.last_voted_round = round;
+        self.last_voted_root = rewards_root;
+        self.last_stake = stake;
+    }
+}
+
+/// Total stake of all ncn operators in a voting round, built by the snapshot cranks
+#[account]
+#[derive(InitSpace)]
+pub struct EpochSnapshot {
+    pub config: Pubkey,
+    pub round: u64,
+    pub operator_count: u64,
+    pub vault_count: u64,
+    pub operators_registered: u64,
+    pub total_stake: u64,
+    /// receives the rent when closed
+    pub payer: Pubkey,
+    /// operator snapshots settled, the ballot box is kept until all registered ones are
+    pub operators_settled: u64,
+}
+
+impl EpochSnapshot {
+    pub fn is_complete(&self) -> bool {
+        self.operators_registered == self.operator_count
+    }
+
+    pub fn is_settled(&self) -> bool {
+        self.operators_settled == self.operators_registered
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VaultStake {
+    pub vault: Pubkey,
+    pub staked_amount: u64,
+}
+
+/// Stake of one operator frozen at snapshot time, votes are weighted by it
+#[account]
+#[derive(InitSpace)]
+pub struct OperatorSnapshot {
+    pub epoch_snapshot: Pubkey,
+    pub operator: Pubkey,
+    pub round: u64,
+    pub operator_fee_bps: u16,
+    pub total_stake: u64,
+    /// root the operator voted for in this round
+    pub voted_root: Option<[u8; 32]>,
+    /// voter state and offence ledger updated for this round
+    pub settled: bool,
+    /// one entry per ncn vault, in ncn vault ticket index order
+    #[max_len(MAX_SNAPSHOT_VAULTS)]
+    pub vault_stakes: Vec<VaultStake>,
+    /// receives the rent when closed
+    pub payer: Pubkey,
+    /// stake of every ncn vault added, the operator is registered in the epoch snapshot
+    pub complete: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum OffenceKind {
+    /// had stake in the round snapshot but did not vote
+    MissedRound,
+    /// voted for a root other than the finalized one
+    LosingVote,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct Offence {
+    pub round: u64,
+    pub kind: OffenceKind,
+    /// snapshot stake of the operator in the round
+    pub stake: u64,
+}
+
+/// Slashable offences of one operator, read by the slashing crank
+#[account]
+#[derive(InitSpace)]
+pub struct OffenceLedger {
+    pub config: Pubkey,
+    pub operator: Pubkey,
+    /// total offences recorded, the crank tracks its progress against it
+    pub offence_count: u64,
+    pub missed_rounds: u64,
+    pub losing_votes: u64,
+    /// latest offences, oldest first
+    #[max_len(MAX_OFFENCES)]
+    pub offences: Vec<Offence>,
+}
+
+impl OffenceLedger {
+    pub fn record(&mut self, offence: Offence) {
+        match offence.kind {
+            OffenceKind::MissedRound => self.missed_rounds += 1,
+            OffenceKind::LosingVote => self.losing_votes += 1,
+        }
+        self.offence_count += 1;
+
+        if self.offences.len() == MAX_OFFENCES {
+            self.offences.remove(0);
+        }
+        self.offences.push(offence);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace, PartialEq, Eq)]
+pub enum JoinKind {
+    Vault,
+    Operator,
+}
+
+/// Roles of the jito ncn, all held by the ncn admin PDA after `initialize_ncn`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum NcnRole {
+    /// hands the whole ncn to `new_admin`, dephy-ncn can not manage it anymore
+    Admin,
+    OperatorAdmin,
+    VaultAdmin,
+    SlasherAdmin,
+    DelegateAdmin,
+    MetadataAdmin,
+    WeightTableAdmin,
+    NcnProgramAdmin,
+}
+
+impl NcnRole {
+    /// Jito role set by `NcnSetSecondaryAdmin`, none for the admin itself
+    pub fn secondary(self) -> Option<NcnAdminRole> {
+        match self {
+            Self::Admin => None,
+            Self::OperatorAdmin => Some(NcnAdminRole::OperatorAdmin),
+            Self::VaultAdmin => Some(NcnAdminRole::VaultAdmin),
+            Self::SlasherAdmin => Some(NcnAdminRole::SlasherAdmin),
+            Self::DelegateAdmin => Some(NcnAdminRole::DelegateAdmin),
+            Self::MetadataAdmin => Some(NcnAdminRole::MetadataAdmin),
+            Self::WeightTableAdmin => Some(NcnAdminRole::WeightTableAdmin),
+            Self::NcnProgramAdmin => Some(NcnAdminRole::NcnProgramAdmin),
+        }
+    }
+}
+
+/// A vault or operator asking the authority to be onboarded
+#[account]
+#[derive(InitSpace)]
+pub struct JoinRequest {
+    pub config: Pubkey,
+    pub kind: JoinKind,
+    /// the vault or operator account
+    pub target: Pubkey,
+    /// admin of the target
+    pub applicant: Pubkey,
+    pub payer: Pubkey,
+    pub approved: bool,
+    pub requested_slot: u64,
+}
+
+impl JoinRequest {
+    /// Unapprove the request at `info` if there is one, so it must be reviewed again
+    pub fn revoke(info: &AccountInfo) -> Result<()> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(());
+        }
+
+        let mut data = info.try_borrow_mut_data()?;
+        let mut join_request = JoinRequest::try_deserialize(&mut &data[..])?;
+        join_request.approved = false;
+        join_request.try_serialize(&mut &mut data[..])
+    }
+}
+
+// dephy-rewards can not depend on this crate, it keeps its own copies
+const _: () = {
+    assert!(bytes_eq(
+        BallotBox::DISCRIMINATOR,
+        &dephy_rewards::NCN_BALLOT_BOX_DISCRIMINATOR
+    ));
+    assert!(bytes_eq(
+        &crate::ID.to_bytes(),
+        &dephy_rewards::DEPHY_NCN_PROGRAM_ID.to_bytes()
+    ));
+};
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
 }
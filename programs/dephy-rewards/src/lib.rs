@@ -1,10 +1,17 @@
 #![allow(unexpected_cfgs)]
 
-use anchor_lang::{prelude::*, solana_program::keccak};
+use anchor_lang::{prelude::*, solana_program::{ed25519_program, keccak, sysvar}};
 use anchor_spl::{associated_token::AssociatedToken, token_interface::{Mint, TokenAccount, TokenInterface}};
 
 declare_id!("BEQB5zna1N4eXTGPLdAVG9HJ1bL8rXSMrR7FdycJ6Zd9");
 
+pub const DEPHY_NCN_PROGRAM_ID: Pubkey = anchor_lang::pubkey!("3AGd4bShjwtx7vNJHJowU6UmRdrjqrchL1Zu8smph5pk");
+/// `dephy_ncn::state::BallotBox::DISCRIMINATOR`
+pub const NCN_BALLOT_BOX_DISCRIMINATOR: [u8; 8] = [155, 169, 156, 8, 92, 14, 24, 101];
+pub const MAX_EXTRA_REWARDS_MINTS: usize = 4;
+/// Root versions that can be claimed against, the current one and the one it replaced
+pub const CLAIMABLE_ROOT_VERSIONS: u64 = 2;
+
 #[program]
 pub mod dephy_rewards {
     use super::*;
@@ -12,6 +19,59 @@ pub mod dephy_rewards {
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         let global_config = &mut ctx.accounts.global_config;
         global_config.admin = ctx.accounts.admin.key();
+        global_config.pending_admin = None;
+
+        Ok(())
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>) -> Result<()> {
+        let global_config = &mut ctx.accounts.global_config;
+        let new_admin = ctx.accounts.new_admin.key();
+
+        // proposing the current admin cancels a pending transfer
+        global_config.pending_admin = if new_admin == global_config.admin {
+            None
+        } else {
+            Some(new_admin)
+        };
+
+        emit!(AdminProposed {
+            admin: global_config.admin,
+            pending_admin: global_config.pending_admin,
+        });
+
+        Ok(())
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        let global_config = &mut ctx.accounts.global_config;
+        require!(
+            global_config.pending_admin == Some(ctx.accounts.new_admin.key()),
+            DephyRewardsError::InvalidAuthority
+        );
+
+        let old_admin = global_config.admin;
+        global_config.admin = ctx.accounts.new_admin.key();
+        global_config.pending_admin = None;
+
+        emit!(AdminAccepted {
+            old_admin,
+            new_admin: global_config.admin,
+        });
+
+        Ok(())
+    }
+
+    /// Grow a global config created before admin transfers to the current layout
+    pub fn migrate_global_config(ctx: Context<MigrateGlobalConfig>) -> Result<()> {
+        let global_config = &ctx.accounts.global_config;
+        let space = GlobalConfig::DISCRIMINATOR.len() + GlobalConfig::INIT_SPACE;
+        require_gt!(space, global_config.data_len(), DephyRewardsError::AlreadyMigrated);
+        grow_account(global_config, space, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+
+        // the zeroed `pending_admin` reads as none
+        let migrated = GlobalConfig::try_deserialize(&mut &global_config.try_borrow_data()?[..])?;
+        require_keys_eq!(migrated.admin, ctx.accounts.admin.key(), DephyRewardsError::InvalidAuthority);
 
         Ok(())
     }
@@ -25,9 +85,64 @@ pub mod dephy_rewards {
         Ok(())
     }
 
+    /// Grow a rewards state created before the root history to the current layout
+    pub fn migrate_rewards_state(ctx: Context<MigrateRewardsState>) -> Result<()> {
+        let rewards_state = &ctx.accounts.rewards_state;
+        let space = RewardsState::DISCRIMINATOR.len() + RewardsState::INIT_SPACE;
+        require_gt!(space, rewards_state.data_len(), DephyRewardsError::AlreadyMigrated);
+        grow_account(rewards_state, space, &ctx.accounts.payer, &ctx.accounts.system_program)?;
+
+        let mut migrated = RewardsState::try_deserialize(&mut &rewards_state.try_borrow_data()?[..])?;
+        require_keys_eq!(migrated.authority, ctx.accounts.authority.key(), DephyRewardsError::InvalidAuthority);
+
+        // roots shorter than the largest `MerkleRoot` variant left old bytes where the appended fields start
+        migrated.version = 0;
+        migrated.extra_rewards_mints = vec![];
+        migrated.try_serialize(&mut &mut rewards_state.try_borrow_mut_data()?[..])?;
+
+        Ok(())
+    }
+
+    /// Pay rewards in another mint as well, from the vault's token account of it
+    pub fn add_rewards_mint(ctx: Context<AddRewardsMint>) -> Result<()> {
+        let rewards_state = &mut ctx.accounts.rewards_state;
+        let rewards_mint = ctx.accounts.rewards_mint.key();
+        require!(!rewards_state.is_rewards_mint(&rewards_mint), DephyRewardsError::InvalidRewardsMint);
+        require_gt!(
+            MAX_EXTRA_REWARDS_MINTS,
+            rewards_state.extra_rewards_mints.len(),
+            DephyRewardsError::TooManyRewardsMints
+        );
+        rewards_state.extra_rewards_mints.push(rewards_mint);
+
+        emit!(RewardsMintAdded {
+            rewards_state: rewards_state.key(),
+            rewards_mint,
+            rewards_token_account: ctx.accounts.rewards_token_account.key(),
+        });
+
+        Ok(())
+    }
+
     pub fn update_merkle_root(ctx: Context<UpdateMerkleRoot>, args: UpdateMerkleRootArgs) -> Result<()> {
         let rewards_state = &mut ctx.accounts.rewards_state;
         rewards_state.merkle_root = args.merkle_root;
+        rewards_state.version += 1;
+
+        // kept for claims during rollover and as the audit trail
+        let merkle_root_version = &mut ctx.accounts.merkle_root_version;
+        merkle_root_version.rewards_state = rewards_state.key();
+        merkle_root_version.version = rewards_state.version;
+        merkle_root_version.merkle_root = rewards_state.merkle_root.clone();
+        merkle_root_version.slot = Clock::get()?.slot;
+        merkle_root_version.total_amount = args.total_amount;
+        merkle_root_version.leaf_count = args.leaf_count;
+
+        emit!(MerkleRootUpdated {
+            rewards_state: rewards_state.key(),
+            version: rewards_state.version,
+            merkle_root: rewards_state.merkle_root.clone(),
+        });
 
         Ok(())
     }
@@ -35,69 +150,135 @@ pub mod dephy_rewards {
     pub fn update_authority(ctx: Context<UpdateAuthority>) -> Result<()> {
         let rewards_state = &mut ctx.accounts.rewards_state;
         let global_config = &ctx.accounts.global_config;
-        if global_config.admin != ctx.accounts.authority.key() {
-            if rewards_state.authority != ctx.accounts.authority.key() {
-                return Err(DephyRewardsError::InvalidAuthority.into());
-            }
+        if global_config.admin != ctx.accounts.authority.key()
+            && rewards_state.authority != ctx.accounts.authority.key()
+        {
+            return Err(DephyRewardsError::InvalidAuthority.into());
         }
 
+        let old_authority = rewards_state.authority;
         rewards_state.authority = ctx.accounts.new_authority.key();
 
+        emit!(AuthorityUpdated {
+            rewards_state: rewards_state.key(),
+            old_authority,
+            new_authority: rewards_state.authority,
+        });
+
         Ok(())
     }
 
     pub fn claim_rewards(ctx: Context<ClaimRewards>, args: ClaimRewardsArgs) -> Result<()> {
-        let leaf = keccak::hashv(&[
-            ctx.accounts.owner.key().as_ref(),
-            &args.total_rewards.to_le_bytes(),
-        ]);
-        let computed_root = recompute_root(leaf.to_bytes(), &args.proof, args.index);
-
-        match ctx.accounts.rewards_state.merkle_root {
-            MerkleRoot::Inplace { hash: merkle_root } => {
-                require!(computed_root == merkle_root, DephyRewardsError::InvalidProof);
-            },
-            MerkleRoot::External { pubkey, offset } => {
-                let merkle_root_account = ctx.accounts.maybe_merkle_root_account.as_deref().unwrap();
-                require_keys_eq!(pubkey, merkle_root_account.key(), DephyRewardsError::InvalidProof);
-
-                let start = offset as usize;
-                if let Some(merkle_root_slice) = merkle_root_account.data.borrow().get(start..start+32) {
-                    let merkle_root: [u8; 32] = merkle_root_slice.try_into().unwrap();
-                    require!(computed_root == merkle_root, DephyRewardsError::InvalidProof);
-                } else {
-                    return Err(DephyRewardsError::InvalidProof.into());
-                }
-            },
-        }
+        let version = verify_claim(
+            &ctx.accounts.rewards_state,
+            ctx.accounts.merkle_root_version.as_deref(),
+            ctx.accounts.maybe_merkle_root_account.as_deref(),
+            ctx.accounts.owner.key,
+            &ctx.accounts.rewards_mint.key(),
+            &args,
+        )?;
 
         let claim_state = &mut ctx.accounts.claim_state;
 
-        // transfer unclaimed rewards tokens
-        let unclaimed_rewards = args.total_rewards - claim_state.claimed_rewards;
+        // transfer unclaimed rewards tokens, totals are cumulative across versions
+        let vested_rewards = args.vested_rewards(Clock::get()?.unix_timestamp);
+        require!(vested_rewards > 0, DephyRewardsError::NothingVested);
+        let unclaimed_rewards = vested_rewards.saturating_sub(claim_state.claimed_rewards);
         require!(unclaimed_rewards > 0, DephyRewardsError::AlreadyClaimed);
 
-        anchor_spl::token_interface::transfer_checked(
-            CpiContext::new_with_signer(
-                ctx.accounts.rewards_token_program.to_account_info(),
-                anchor_spl::token_interface::TransferChecked {
-                    mint: ctx.accounts.rewards_mint.to_account_info(),
-                    from: ctx.accounts.rewards_token_account.to_account_info(),
-                    to: ctx.accounts.beneficiary_token_account.to_account_info(),
-                    authority: ctx.accounts.rewards_vault.to_account_info(),
-                },
-                &[&[
-                    b"rewards_vault",
-                    ctx.accounts.rewards_state.key().as_ref(),
-                    &[ctx.bumps.rewards_vault],
-                ]],
-            ),
+        transfer_from_vault(
+            ctx.accounts.rewards_token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                mint: ctx.accounts.rewards_mint.to_account_info(),
+                from: ctx.accounts.rewards_token_account.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.rewards_vault.to_account_info(),
+            },
+            &ctx.accounts.rewards_state.key(),
+            ctx.bumps.rewards_vault,
             unclaimed_rewards,
             ctx.accounts.rewards_mint.decimals,
         )?;
 
         claim_state.owner = ctx.accounts.owner.key();
-        claim_state.claimed_rewards = args.total_rewards;
+        claim_state.mint = ctx.accounts.rewards_mint.key();
+        claim_state.claimed_rewards = vested_rewards;
+        claim_state.last_claimed_version = version;
+
+        emit!(RewardsClaimed {
+            rewards_state: ctx.accounts.rewards_state.key(),
+            version,
+            owner: claim_state.owner,
+            mint: claim_state.mint,
+            beneficiary_token_account: ctx.accounts.beneficiary_token_account.key(),
+            amount: unclaimed_rewards,
+            total_claimed: claim_state.claimed_rewards,
+        });
+
+        Ok(())
+    }
+
+    /// Claim submitted and paid by a relayer, the owner signs `claim_for_message`
+    /// in an Ed25519 program instruction right before this one
+    pub fn claim_rewards_for(ctx: Context<ClaimRewardsFor>, args: ClaimRewardsForArgs) -> Result<()> {
+        let owner = ctx.accounts.owner.key();
+        let claim_state = &mut ctx.accounts.claim_state;
+        require_eq!(args.nonce, claim_state.nonce, DephyRewardsError::InvalidNonce);
+
+        verify_ed25519_signature(
+            &ctx.accounts.instructions_sysvar,
+            &owner,
+            &claim_for_message(
+                &ctx.accounts.rewards_state.key(),
+                args.claim.total_rewards,
+                &ctx.accounts.beneficiary_token_account.key(),
+                args.nonce,
+            ),
+        )?;
+
+        let version = verify_claim(
+            &ctx.accounts.rewards_state,
+            ctx.accounts.merkle_root_version.as_deref(),
+            ctx.accounts.maybe_merkle_root_account.as_deref(),
+            &owner,
+            &ctx.accounts.rewards_mint.key(),
+            &args.claim,
+        )?;
+
+        let vested_rewards = args.claim.vested_rewards(Clock::get()?.unix_timestamp);
+        require!(vested_rewards > 0, DephyRewardsError::NothingVested);
+        let unclaimed_rewards = vested_rewards.saturating_sub(claim_state.claimed_rewards);
+        require!(unclaimed_rewards > 0, DephyRewardsError::AlreadyClaimed);
+
+        transfer_from_vault(
+            ctx.accounts.rewards_token_program.to_account_info(),
+            anchor_spl::token_interface::TransferChecked {
+                mint: ctx.accounts.rewards_mint.to_account_info(),
+                from: ctx.accounts.rewards_token_account.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.rewards_vault.to_account_info(),
+            },
+            &ctx.accounts.rewards_state.key(),
+            ctx.bumps.rewards_vault,
+            unclaimed_rewards,
+            ctx.accounts.rewards_mint.decimals,
+        )?;
+
+        claim_state.owner = owner;
+        claim_state.mint = ctx.accounts.rewards_mint.key();
+        claim_state.claimed_rewards = vested_rewards;
+        claim_state.last_claimed_version = version;
+        claim_state.nonce += 1;
+
+        emit!(RewardsClaimed {
+            rewards_state: ctx.accounts.rewards_state.key(),
+            version,
+            owner,
+            mint: claim_state.mint,
+            beneficiary_token_account: ctx.accounts.beneficiary_token_account.key(),
+            amount: unclaimed_rewards,
+            total_claimed: claim_state.claimed_rewards,
+        });
 
         Ok(())
     }
@@ -117,6 +298,34 @@ pub struct Initialize<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    #[account(mut, seeds = [b"global_config"], bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+    #[account(address = global_config.admin @ DephyRewardsError::InvalidAuthority)]
+    pub admin: Signer<'info>,
+    /// CHECK: new admin
+    pub new_admin: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    #[account(mut, seeds = [b"global_config"], bump)]
+    pub global_config: Account<'info, GlobalConfig>,
+    pub new_admin: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateGlobalConfig<'info> {
+    /// CHECK: deserialized once grown
+    #[account(mut, owner = crate::ID, seeds = [b"global_config"], bump)]
+    pub global_config: UncheckedAccount<'info>,
+    pub admin: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
 
 #[derive(Accounts)]
 pub struct InitializeRewardsState<'info> {
@@ -142,17 +351,65 @@ pub struct InitializeRewardsState<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+pub struct MigrateRewardsState<'info> {
+    /// CHECK: deserialized once grown
+    #[account(mut, owner = crate::ID)]
+    pub rewards_state: UncheckedAccount<'info>,
+    pub authority: Signer<'info>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AddRewardsMint<'info> {
+    #[account(mut)]
+    pub rewards_state: Account<'info, RewardsState>,
+    #[account(address = rewards_state.authority @ DephyRewardsError::InvalidAuthority)]
+    pub authority: Signer<'info>,
+    #[account(seeds = [b"rewards_vault", rewards_state.key().as_ref()], bump)]
+    pub rewards_vault: SystemAccount<'info>,
+    #[account(mint::token_program = rewards_token_program)]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init_if_needed, payer = payer,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = rewards_vault,
+        associated_token::token_program = rewards_token_program
+    )]
+    pub rewards_token_account: InterfaceAccount<'info, TokenAccount>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rewards_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 pub struct UpdateMerkleRoot<'info> {
     #[account(mut)]
     pub rewards_state: Account<'info, RewardsState>,
     #[account(address = rewards_state.authority @ DephyRewardsError::InvalidAuthority)]
     pub authority: Signer<'info>,
+    #[account(
+        init, payer = payer,
+        space = MerkleRootVersion::DISCRIMINATOR.len() + MerkleRootVersion::INIT_SPACE,
+        seeds = [b"merkle_root_version", rewards_state.key().as_ref(), &(rewards_state.version + 1).to_le_bytes()],
+        bump
+    )]
+    pub merkle_root_version: Account<'info, MerkleRootVersion>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateMerkleRootArgs {
-    merkle_root: MerkleRoot,
+    pub merkle_root: MerkleRoot,
+    /// sum of the leaf totals, informational
+    pub total_amount: u64,
+    pub leaf_count: u32,
 }
 
 #[derive(Accounts)]
@@ -172,15 +429,26 @@ pub struct ClaimRewards<'info> {
     pub rewards_state: Account<'info, RewardsState>,
     #[account(seeds = [b"rewards_vault", rewards_state.key().as_ref()], bump)]
     pub rewards_vault: SystemAccount<'info>,
-    #[account(mint::token_program = rewards_token_program)]
+    #[account(
+        mint::token_program = rewards_token_program,
+        constraint = rewards_state.is_rewards_mint(&rewards_mint.key()) @ DephyRewardsError::InvalidRewardsMint
+    )]
     pub rewards_mint: InterfaceAccount<'info, Mint>,
-    #[account(mut, address = rewards_state.rewards_token_account)]
+    #[account(
+        mut,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = rewards_vault,
+        associated_token::token_program = rewards_token_program
+    )]
     pub rewards_token_account: InterfaceAccount<'info, TokenAccount>,
     pub owner: Signer<'info>,
     #[account(
         init_if_needed, payer = payer,
-        space = RewardsState::DISCRIMINATOR.len() + RewardsState::INIT_SPACE,
-        seeds = [b"claim_state", rewards_state.key().as_ref(), owner.key().as_ref()],
+        space = ClaimState::DISCRIMINATOR.len() + ClaimState::INIT_SPACE,
+        seeds = [
+            b"claim_state", rewards_state.key().as_ref(), owner.key().as_ref(),
+            rewards_state.claim_state_mint_seed(&rewards_mint.key())
+        ],
         bump
     )]
     pub claim_state: Account<'info, ClaimState>,
@@ -188,6 +456,9 @@ pub struct ClaimRewards<'info> {
     pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
     /// CHECK:
     pub maybe_merkle_root_account: Option<UncheckedAccount<'info>>,
+    /// claim against this recent version instead of the current root
+    #[account(has_one = rewards_state @ DephyRewardsError::InvalidMerkleRootAccount)]
+    pub merkle_root_version: Option<Account<'info, MerkleRootVersion>>,
     #[account(mut)]
     pub payer: Signer<'info>,
     pub rewards_token_program: Interface<'info, TokenInterface>,
@@ -199,6 +470,93 @@ pub struct ClaimRewardsArgs {
     pub index: u32,
     pub total_rewards: u64,
     pub proof: Vec<[u8; 32]>,
+    /// schedule of the leaf, none for rewards unlocked at once
+    pub vesting: Option<VestingSchedule>,
+}
+
+impl ClaimRewardsArgs {
+    pub fn vested_rewards(&self, now: i64) -> u64 {
+        match &self.vesting {
+            Some(vesting) => vesting.vested(self.total_rewards, now),
+            None => self.total_rewards,
+        }
+    }
+}
+
+/// Nothing unlocks before the cliff, then linearly from start to end, unix timestamps
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VestingSchedule {
+    pub start_ts: i64,
+    pub cliff_ts: i64,
+    pub end_ts: i64,
+}
+
+impl VestingSchedule {
+    pub fn vested(&self, total: u64, now: i64) -> u64 {
+        if now < self.cliff_ts {
+            return 0;
+        }
+        if now >= self.end_ts || self.end_ts <= self.start_ts {
+            return total;
+        }
+
+        let elapsed = (now as i128 - self.start_ts as i128).max(0);
+        let duration = self.end_ts as i128 - self.start_ts as i128;
+        (total as i128 * elapsed / duration) as u64
+    }
+}
+
+#[derive(Accounts)]
+pub struct ClaimRewardsFor<'info> {
+    #[account()]
+    pub rewards_state: Account<'info, RewardsState>,
+    #[account(seeds = [b"rewards_vault", rewards_state.key().as_ref()], bump)]
+    pub rewards_vault: SystemAccount<'info>,
+    #[account(
+        mint::token_program = rewards_token_program,
+        constraint = rewards_state.is_rewards_mint(&rewards_mint.key()) @ DephyRewardsError::InvalidRewardsMint
+    )]
+    pub rewards_mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = rewards_mint,
+        associated_token::authority = rewards_vault,
+        associated_token::token_program = rewards_token_program
+    )]
+    pub rewards_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK: signs the claim off-chain
+    pub owner: UncheckedAccount<'info>,
+    #[account(
+        init_if_needed, payer = payer,
+        space = ClaimState::DISCRIMINATOR.len() + ClaimState::INIT_SPACE,
+        seeds = [
+            b"claim_state", rewards_state.key().as_ref(), owner.key().as_ref(),
+            rewards_state.claim_state_mint_seed(&rewards_mint.key())
+        ],
+        bump
+    )]
+    pub claim_state: Account<'info, ClaimState>,
+    #[account(mut, token::mint = rewards_mint.key(), token::token_program = rewards_token_program)]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+    /// CHECK:
+    pub maybe_merkle_root_account: Option<UncheckedAccount<'info>>,
+    #[account(has_one = rewards_state @ DephyRewardsError::InvalidMerkleRootAccount)]
+    pub merkle_root_version: Option<Account<'info, MerkleRootVersion>>,
+    /// CHECK: instructions sysvar
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    /// the relayer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub rewards_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ClaimRewardsForArgs {
+    pub claim: ClaimRewardsArgs,
+    /// must equal `ClaimState.nonce`
+    pub nonce: u64,
 }
 
 
@@ -210,6 +568,38 @@ pub enum MerkleRoot {
     External {
         pubkey: Pubkey,
         offset: u64,
+    },
+    /// Winning root of a dephy-ncn voting round, claimable until dephy-ncn closes the ballot box
+    /// after its `ballot_retention_rounds`
+    NcnBallotBox {
+        config: Pubkey,
+        round: u64,
+    },
+}
+
+/// Leading fields of `dephy_ncn::state::BallotBox`, dephy-ncn keeps them in this order
+#[derive(AnchorDeserialize)]
+pub struct NcnBallotBoxHeader {
+    pub config: Pubkey,
+    pub round: u64,
+    pub payer: Pubkey,
+    pub operators_voted: u64,
+    pub total_votes: u64,
+    pub rewards_root: [u8; 32],
+    pub finalized_slot: u64,
+}
+
+impl NcnBallotBoxHeader {
+    pub fn load(info: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(*info.owner, DEPHY_NCN_PROGRAM_ID, DephyRewardsError::InvalidMerkleRootAccount);
+
+        let data = info.try_borrow_data()?;
+        require!(
+            data.starts_with(&NCN_BALLOT_BOX_DISCRIMINATOR),
+            DephyRewardsError::InvalidMerkleRootAccount
+        );
+        Self::deserialize(&mut &data[NCN_BALLOT_BOX_DISCRIMINATOR.len()..])
+            .map_err(|_| DephyRewardsError::InvalidMerkleRootAccount.into())
     }
 }
 
@@ -217,6 +607,7 @@ pub enum MerkleRoot {
 #[derive(InitSpace)]
 pub struct GlobalConfig {
     pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
 }
 
 #[account]
@@ -226,13 +617,98 @@ pub struct RewardsState {
     pub rewards_mint: Pubkey,
     pub rewards_token_account: Pubkey,
     pub merkle_root: MerkleRoot,
+    // fields below are appended, older accounts are grown by `migrate_rewards_state`
+    /// version of `merkle_root`, zero before the first one
+    pub version: u64,
+    /// paid from the rewards vault's associated token accounts, leaves commit to the mint
+    #[max_len(MAX_EXTRA_REWARDS_MINTS)]
+    pub extra_rewards_mints: Vec<Pubkey>,
+}
+
+impl RewardsState {
+    pub fn is_rewards_mint(&self, mint: &Pubkey) -> bool {
+        self.rewards_mint == *mint || self.extra_rewards_mints.contains(mint)
+    }
+
+    /// Claim states of the primary mint keep their seeds from before extra mints, only
+    /// the extra mints are added to the seeds
+    pub fn claim_state_mint_seed<'a>(&self, mint: &'a Pubkey) -> &'a [u8] {
+        if *mint == self.rewards_mint {
+            &[]
+        } else {
+            mint.as_ref()
+        }
+    }
+}
+
+/// Every root a rewards state had
+#[account]
+#[derive(InitSpace)]
+pub struct MerkleRootVersion {
+    pub rewards_state: Pubkey,
+    pub version: u64,
+    pub merkle_root: MerkleRoot,
+    /// slot the root went live
+    pub slot: u64,
+    pub total_amount: u64,
+    pub leaf_count: u32,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct ClaimState {
     pub owner: Pubkey,
+    /// cumulative, root totals only grow
     pub claimed_rewards: u64,
+    pub last_claimed_version: u64,
+    /// next nonce of a relayed claim
+    pub nonce: u64,
+    /// claim states of the primary mint from before extra mints get it on their next claim
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Option<Pubkey>,
+}
+
+#[event]
+pub struct AdminAccepted {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct MerkleRootUpdated {
+    pub rewards_state: Pubkey,
+    pub version: u64,
+    pub merkle_root: MerkleRoot,
+}
+
+#[event]
+pub struct AuthorityUpdated {
+    pub rewards_state: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RewardsMintAdded {
+    pub rewards_state: Pubkey,
+    pub rewards_mint: Pubkey,
+    pub rewards_token_account: Pubkey,
+}
+
+#[event]
+pub struct RewardsClaimed {
+    pub rewards_state: Pubkey,
+    pub version: u64,
+    pub owner: Pubkey,
+    pub mint: Pubkey,
+    pub beneficiary_token_account: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
 }
 
 #[error_code]
@@ -243,11 +719,219 @@ pub enum DephyRewardsError {
     InvalidProof,
     #[msg("Already claimed")]
     AlreadyClaimed,
+    #[msg("Invalid merkle root account")]
+    InvalidMerkleRootAccount,
+    #[msg("Consensus not reached")]
+    ConsensusNotReached,
+    #[msg("Merkle root version no longer claimable")]
+    RootVersionExpired,
+    #[msg("Invalid owner signature")]
+    InvalidSignature,
+    #[msg("Invalid nonce")]
+    InvalidNonce,
+    #[msg("Nothing vested yet")]
+    NothingVested,
+    #[msg("Invalid rewards mint")]
+    InvalidRewardsMint,
+    #[msg("Too many rewards mints")]
+    TooManyRewardsMints,
+    #[msg("Account already migrated")]
+    AlreadyMigrated,
+}
+
+
+/// Leaf of the rewards tree: owner, the mint unless it is the primary `rewards_mint`,
+/// total_rewards (le), and the vesting schedule start, cliff, end (le) when there is one
+pub fn hash_leaf(
+    owner: &Pubkey,
+    mint: Option<&Pubkey>,
+    total_rewards: u64,
+    vesting: Option<&VestingSchedule>,
+) -> Node {
+    let total_rewards = total_rewards.to_le_bytes();
+    let mut data: Vec<&[u8]> = vec![owner.as_ref()];
+    if let Some(mint) = mint {
+        data.push(mint.as_ref());
+    }
+    data.push(&total_rewards);
+
+    let vesting = vesting.map(|vesting| [
+        vesting.start_ts.to_le_bytes(),
+        vesting.cliff_ts.to_le_bytes(),
+        vesting.end_ts.to_le_bytes(),
+    ]);
+    if let Some(vesting) = &vesting {
+        data.extend(vesting.iter().map(|ts| ts.as_ref()));
+    }
+
+    keccak::hashv(&data).to_bytes()
+}
+
+/// Top up the rent of an account for `space` and grow it, the added bytes are zeroed
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    space: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space).saturating_sub(account.lamports());
+    if lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+    account.realloc(space, true)?;
+
+    Ok(())
+}
+
+/// Check the proof of a claim against the current root, or the given recent version
+fn verify_claim(
+    rewards_state: &RewardsState,
+    merkle_root_version: Option<&MerkleRootVersion>,
+    maybe_merkle_root_account: Option<&AccountInfo>,
+    owner: &Pubkey,
+    mint: &Pubkey,
+    args: &ClaimRewardsArgs,
+) -> Result<u64> {
+    let leaf_mint = (*mint != rewards_state.rewards_mint).then_some(mint);
+    let leaf = hash_leaf(owner, leaf_mint, args.total_rewards, args.vesting.as_ref());
+    let computed_root = recompute_root(leaf, &args.proof, args.index);
+
+    let (version, merkle_root) = match merkle_root_version {
+        Some(merkle_root_version) => {
+            require!(
+                merkle_root_version.version + CLAIMABLE_ROOT_VERSIONS > rewards_state.version,
+                DephyRewardsError::RootVersionExpired
+            );
+            (merkle_root_version.version, &merkle_root_version.merkle_root)
+        },
+        None => (rewards_state.version, &rewards_state.merkle_root),
+    };
+
+    match *merkle_root {
+        MerkleRoot::Inplace { hash: merkle_root } => {
+            require!(computed_root == merkle_root, DephyRewardsError::InvalidProof);
+        },
+        MerkleRoot::External { pubkey, offset } => {
+            let merkle_root_account = maybe_merkle_root_account
+                .ok_or(DephyRewardsError::InvalidMerkleRootAccount)?;
+            require_keys_eq!(pubkey, merkle_root_account.key(), DephyRewardsError::InvalidProof);
+
+            let start = offset as usize;
+            if let Some(merkle_root_slice) = merkle_root_account.data.borrow().get(start..start+32) {
+                let merkle_root: [u8; 32] = merkle_root_slice.try_into().unwrap();
+                require!(computed_root == merkle_root, DephyRewardsError::InvalidProof);
+            } else {
+                return Err(DephyRewardsError::InvalidProof.into());
+            }
+        },
+        MerkleRoot::NcnBallotBox { config, round } => {
+            let ballot_box_account = maybe_merkle_root_account
+                .ok_or(DephyRewardsError::InvalidMerkleRootAccount)?;
+            let ballot_box = NcnBallotBoxHeader::load(ballot_box_account)?;
+            require!(
+                ballot_box.config == config && ballot_box.round == round,
+                DephyRewardsError::InvalidMerkleRootAccount
+            );
+            require!(ballot_box.finalized_slot > 0, DephyRewardsError::ConsensusNotReached);
+            require!(computed_root == ballot_box.rewards_root, DephyRewardsError::InvalidProof);
+        },
+    }
+
+    Ok(version)
+}
+
+fn transfer_from_vault<'info>(
+    token_program: AccountInfo<'info>,
+    accounts: anchor_spl::token_interface::TransferChecked<'info>,
+    rewards_state: &Pubkey,
+    rewards_vault_bump: u8,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    anchor_spl::token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            token_program,
+            accounts,
+            &[&[b"rewards_vault", rewards_state.as_ref(), &[rewards_vault_bump]]],
+        ),
+        amount,
+        decimals,
+    )
+}
+
+/// Message an owner signs to let a relayer submit `claim_rewards_for`:
+/// rewards_state, total_rewards (le), beneficiary token account, nonce (le)
+pub fn claim_for_message(rewards_state: &Pubkey, total_rewards: u64, beneficiary: &Pubkey, nonce: u64) -> Vec<u8> {
+    [
+        rewards_state.as_ref(),
+        &total_rewards.to_le_bytes(),
+        beneficiary.as_ref(),
+        &nonce.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// The instruction before the current one must be an Ed25519 program instruction
+/// verifying a single signature of `signer` over `message`, with all data inline
+fn verify_ed25519_signature(instructions_sysvar: &AccountInfo, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    // layout of `solana_sdk::ed25519_instruction`
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    const PUBKEY_LEN: usize = 32;
+    const SIGNATURE_LEN: usize = 64;
+
+    let current_index = sysvar::instructions::load_current_index_checked(instructions_sysvar)?;
+    let index = current_index.checked_sub(1).ok_or(DephyRewardsError::InvalidSignature)?;
+    let ix = sysvar::instructions::load_instruction_at_checked(index as usize, instructions_sysvar)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, DephyRewardsError::InvalidSignature);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= OFFSETS_START + OFFSETS_LEN && data[0] == 1,
+        DephyRewardsError::InvalidSignature
+    );
+    let read_u16 = |at: usize| u16::from_le_bytes([data[OFFSETS_START + at], data[OFFSETS_START + at + 1]]);
+    let signature_offset = read_u16(0) as usize;
+    let signature_ix_index = read_u16(2);
+    let pubkey_offset = read_u16(4) as usize;
+    let pubkey_ix_index = read_u16(6);
+    let message_offset = read_u16(8) as usize;
+    let message_size = read_u16(10) as usize;
+    let message_ix_index = read_u16(12);
+
+    // u16::MAX refers to the Ed25519 instruction itself
+    require!(
+        signature_ix_index == u16::MAX && pubkey_ix_index == u16::MAX && message_ix_index == u16::MAX,
+        DephyRewardsError::InvalidSignature
+    );
+    require!(
+        data.len() >= signature_offset + SIGNATURE_LEN,
+        DephyRewardsError::InvalidSignature
+    );
+    require!(
+        data.get(pubkey_offset..pubkey_offset + PUBKEY_LEN) == Some(signer.as_ref()),
+        DephyRewardsError::InvalidSignature
+    );
+    require!(
+        data.get(message_offset..message_offset + message_size) == Some(message),
+        DephyRewardsError::InvalidSignature
+    );
+
+    Ok(())
 }
 
 
 // from spl-merkle-tree-reference
-type Node = [u8; 32];
+pub type Node = [u8; 32];
 
 pub fn recompute_root(mut leaf: Node, proof: &[Node], index: u32) -> Node {
     for (i, s) in proof.iter().enumerate() {
@@ -1,5 +1,7 @@
-import { AnchorProvider, Program, web3, workspace } from '@coral-xyz/anchor';
+import { AnchorProvider, BN, Program, web3, workspace } from '@coral-xyz/anchor';
+import * as spl from '@solana/spl-token';
 import { DephyNcn } from '../target/types/dephy_ncn';
+import { DephyRewards } from '../target/types/dephy_rewards';
 
 const JITO_RESTAKING_ID = new web3.PublicKey("RestkWeAVL8fRGgzhfeoqFhsqKRchg6aa1XrcH96z4Q");
 const JITO_VAULT_ID = new web3.PublicKey("Vau1t6sLNxnzB7ZDsef8TLbPLfyZMYXH8WTNqUdm9g8");
@@ -9,6 +11,100 @@ export function getDephyNcnProgram() {
   return workspace.DephyNcn as Program<DephyNcn>;
 }
 
+export function getDephyRewardsProgram() {
+  return workspace.DephyRewards as Program<DephyRewards>;
+}
+
+// same as `VotingPeriod::round`
+export async function currentRound(provider: AnchorProvider, configPubkey: web3.PublicKey) {
+  const dephyNcn = getDephyNcnProgram();
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+  const slot = await provider.connection.getSlot();
+
+  if (configAccount.votingPeriod.slots) {
+    return Math.floor(slot / configAccount.votingPeriod.slots.length.toNumber());
+  }
+
+  const [jitoRestakingConfig] = web3.PublicKey.findProgramAddressSync([Buffer.from('config')], JITO_RESTAKING_ID);
+  const { data } = await provider.connection.getAccountInfo(jitoRestakingConfig);
+  // discriminator, admin, vault_program, ncn_count, operator_count, epoch_length
+  const epochLength = data.readBigUInt64LE(8 + 32 + 32 + 8 + 8);
+  return Number(BigInt(slot) / epochLength);
+}
+
+export function findEpochSnapshot(configPubkey: web3.PublicKey, round: number) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('epoch_snapshot'), configPubkey.toBuffer(), new BN(round).toArrayLike(Buffer, 'le', 8)],
+    getDephyNcnProgram().programId
+  )[0];
+}
+
+export function findRewardsState(configPubkey: web3.PublicKey) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('rewards_state'), configPubkey.toBuffer()],
+    getDephyNcnProgram().programId
+  )[0];
+}
+
+export function findMerkleRootVersion(rewardsStatePubkey: web3.PublicKey, version: number) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('merkle_root_version'), rewardsStatePubkey.toBuffer(), new BN(version).toArrayLike(Buffer, 'le', 8)],
+    getDephyRewardsProgram().programId
+  )[0];
+}
+
+export function findJoinRequest(configPubkey: web3.PublicKey, target: web3.PublicKey) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('join_request'), configPubkey.toBuffer(), target.toBuffer()],
+    getDephyNcnProgram().programId
+  )[0];
+}
+
+// signed by the authority, or by anyone once the join request of the target is approved
+function onboardingAccounts(provider: AnchorProvider, configPubkey: web3.PublicKey, target: web3.PublicKey, joinRequest?: boolean) {
+  return joinRequest
+    ? { authority: null, joinRequest: findJoinRequest(configPubkey, target) }
+    : { authority: provider.publicKey, joinRequest: null };
+}
+
+// the warmup closes the join request it uses, refunding whoever paid for it
+async function warmupAccounts(provider: AnchorProvider, configPubkey: web3.PublicKey, target: web3.PublicKey, joinRequest?: boolean) {
+  const accounts = onboardingAccounts(provider, configPubkey, target, joinRequest);
+  if (!accounts.joinRequest) {
+    return { ...accounts, joinRequestPayer: null };
+  }
+
+  const joinRequestAccount = await getDephyNcnProgram().account.joinRequest.fetch(accounts.joinRequest);
+  return { ...accounts, joinRequestPayer: joinRequestAccount.payer };
+}
+
+export function findBallotBox(configPubkey: web3.PublicKey, round: number) {
+  return web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('ballot_box'), configPubkey.toBuffer(), new BN(round).toArrayLike(Buffer, 'le', 8)],
+    getDephyNcnProgram().programId
+  )[0];
+}
+
+// remaining accounts of snapshot_operator, in the order of `VAULT_STAKE_ACCOUNTS_LEN`
+// remaining accounts of `snapshot_operator_vault`
+export function vaultStakeAccounts(ncn: web3.PublicKey, operator: web3.PublicKey, vault: web3.PublicKey) {
+  return [
+    vault,
+    web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('operator_vault_ticket'), operator.toBuffer(), vault.toBuffer()], JITO_RESTAKING_ID
+    )[0],
+    web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('vault_operator_delegation'), vault.toBuffer(), operator.toBuffer()], JITO_VAULT_ID
+    )[0],
+    web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('ncn_vault_ticket'), ncn.toBuffer(), vault.toBuffer()], JITO_RESTAKING_ID
+    )[0],
+    web3.PublicKey.findProgramAddressSync(
+      [Buffer.from('vault_ncn_ticket'), vault.toBuffer(), ncn.toBuffer()], JITO_VAULT_ID
+    )[0],
+  ].map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+}
+
 export function debugPubkeys(pubkeys: Record<string, any>) {
   for (const name in pubkeys) {
     console.log(name, pubkeys[name].toString());
@@ -37,9 +133,86 @@ export async function initializeNcn(provider: AnchorProvider, opts: InitializeNc
 }
 
 
+export interface InitializeRewardsOpts {
+  config: string;
+  rewardsMint: string;
+}
+export async function initializeRewards(provider: AnchorProvider, opts: InitializeRewardsOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const rewardsMintPubkey = new web3.PublicKey(opts['rewardsMint']);
+
+  const rewardsMintAccount = await provider.connection.getAccountInfo(rewardsMintPubkey);
+  const rewardsState = findRewardsState(configPubkey);
+  const [rewardsVault] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('rewards_vault'), rewardsState.toBuffer()],
+    getDephyRewardsProgram().programId
+  );
+  const rewardsTokenAccount = spl.getAssociatedTokenAddressSync(
+    rewardsMintPubkey, rewardsVault, true, rewardsMintAccount.owner
+  );
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .initializeRewards()
+    .accountsPartial({
+      config: configPubkey,
+      rewardsState,
+      rewardsVault,
+      rewardsMint: rewardsMintPubkey,
+      rewardsTokenAccount,
+      authority: provider.publicKey,
+      rewardsTokenProgram: rewardsMintAccount.owner,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface AddRewardsMintOpts {
+  config: string;
+  rewardsMint: string;
+}
+export async function addRewardsMint(provider: AnchorProvider, opts: AddRewardsMintOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const rewardsMintPubkey = new web3.PublicKey(opts['rewardsMint']);
+
+  const rewardsMintAccount = await provider.connection.getAccountInfo(rewardsMintPubkey);
+  const rewardsState = findRewardsState(configPubkey);
+  const [rewardsVault] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('rewards_vault'), rewardsState.toBuffer()],
+    getDephyRewardsProgram().programId
+  );
+  const rewardsTokenAccount = spl.getAssociatedTokenAddressSync(
+    rewardsMintPubkey, rewardsVault, true, rewardsMintAccount.owner
+  );
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .addRewardsMint()
+    .accountsPartial({
+      config: configPubkey,
+      rewardsState,
+      rewardsVault,
+      rewardsMint: rewardsMintPubkey,
+      rewardsTokenAccount,
+      authority: provider.publicKey,
+      rewardsTokenProgram: rewardsMintAccount.owner,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
 export interface InitializeVaultOpts {
   config: string;
   vault: string;
+  joinRequest?: boolean;
 }
 export async function initializeVault(provider: AnchorProvider, opts: InitializeVaultOpts) {
   const dephyNcn = getDephyNcnProgram();
@@ -50,10 +223,11 @@ export async function initializeVault(provider: AnchorProvider, opts: Initialize
 
   const { signature, pubkeys } = await dephyNcn.methods
     .initializeVault()
-    .accounts({
+    .accountsPartial({
       config: configPubkey,
       ncn: configAccount.ncn,
       vault: vaultPubkey,
+      ...onboardingAccounts(provider, configPubkey, vaultPubkey, opts.joinRequest),
     })
     .rpcAndKeys()
 
@@ -65,6 +239,7 @@ export async function initializeVault(provider: AnchorProvider, opts: Initialize
 export interface WarmupVaultOpts {
   config: string;
   vault: string;
+  joinRequest?: boolean;
 }
 export async function warmupVault(provider: AnchorProvider, opts: WarmupVaultOpts) {
   const dephyNcn = getDephyNcnProgram();
@@ -75,10 +250,167 @@ export async function warmupVault(provider: AnchorProvider, opts: WarmupVaultOpt
 
   const { signature, pubkeys } = await dephyNcn.methods
     .warmupVault()
+    .accountsPartial({
+      config: configPubkey,
+      ncn: configAccount.ncn,
+      vault: vaultPubkey,
+      ...(await warmupAccounts(provider, configPubkey, vaultPubkey, opts.joinRequest)),
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface RequestJoinOpts {
+  config: string;
+  kind: 'vault' | 'operator';
+  target: string;
+}
+export async function requestJoin(provider: AnchorProvider, opts: RequestJoinOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .requestJoin({ kind: opts.kind === 'vault' ? { vault: {} } : { operator: {} } })
+    .accounts({
+      config: configPubkey,
+      target: new web3.PublicKey(opts['target']),
+      applicant: provider.publicKey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface ReviewJoinRequestOpts {
+  config: string;
+  target: string;
+  reject?: boolean;
+}
+export async function reviewJoinRequest(provider: AnchorProvider, opts: ReviewJoinRequestOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const joinRequest = findJoinRequest(configPubkey, new web3.PublicKey(opts['target']));
+  const joinRequestAccount = await dephyNcn.account.joinRequest.fetch(joinRequest);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .reviewJoinRequest({ approve: !opts.reject })
+    .accounts({
+      config: configPubkey,
+      joinRequest,
+      authority: provider.publicKey,
+      payer: joinRequestAccount.payer,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface InitializeVaultSlasherOpts {
+  config: string;
+  vault: string;
+  maxSlashablePerEpoch: number;
+}
+export async function initializeVaultSlasher(provider: AnchorProvider, opts: InitializeVaultSlasherOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const vaultPubkey = new web3.PublicKey(opts['vault'])
+
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .initializeVaultSlasher({
+      maxSlashablePerEpoch: new BN(opts.maxSlashablePerEpoch),
+    })
+    .accounts({
+      config: configPubkey,
+      ncn: configAccount.ncn,
+      vault: vaultPubkey,
+      authority: provider.publicKey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface WarmupVaultSlasherOpts {
+  config: string;
+  vault: string;
+}
+export async function warmupVaultSlasher(provider: AnchorProvider, opts: WarmupVaultSlasherOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const vaultPubkey = new web3.PublicKey(opts['vault'])
+
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .warmupVaultSlasher()
+    .accounts({
+      config: configPubkey,
+      ncn: configAccount.ncn,
+      vault: vaultPubkey,
+      authority: provider.publicKey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface CooldownVaultOpts {
+  config: string;
+  vault: string;
+}
+export async function cooldownVault(provider: AnchorProvider, opts: CooldownVaultOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const vaultPubkey = new web3.PublicKey(opts['vault'])
+
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .cooldownVault()
+    .accounts({
+      config: configPubkey,
+      ncn: configAccount.ncn,
+      vault: vaultPubkey,
+      authority: provider.publicKey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface CooldownVaultSlasherOpts {
+  config: string;
+  vault: string;
+}
+export async function cooldownVaultSlasher(provider: AnchorProvider, opts: CooldownVaultSlasherOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const vaultPubkey = new web3.PublicKey(opts['vault'])
+
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .cooldownVaultSlasher()
     .accounts({
       config: configPubkey,
       ncn: configAccount.ncn,
       vault: vaultPubkey,
+      authority: provider.publicKey,
     })
     .rpcAndKeys()
 
@@ -91,6 +423,7 @@ export async function warmupVault(provider: AnchorProvider, opts: WarmupVaultOpt
 export interface InitializeOperatorOpts {
   config: string;
   operator: string;
+  joinRequest?: boolean;
 }
 export async function initializeOperator(provider: AnchorProvider, opts: InitializeOperatorOpts) {
   const dephyNcn = getDephyNcnProgram();
@@ -101,10 +434,11 @@ export async function initializeOperator(provider: AnchorProvider, opts: Initial
 
   const { signature, pubkeys } = await dephyNcn.methods
     .initializeOperator()
-    .accounts({
+    .accountsPartial({
       config: configPubkey,
       ncn: configAccount.ncn,
       operator: operatorPubkey,
+      ...onboardingAccounts(provider, configPubkey, operatorPubkey, opts.joinRequest),
     })
     .rpcAndKeys()
 
@@ -117,6 +451,7 @@ export async function initializeOperator(provider: AnchorProvider, opts: Initial
 export interface WarmupOperatorOpts {
   config: string;
   operator: string;
+  joinRequest?: boolean;
 }
 export async function warmupOperator(provider: AnchorProvider, opts: WarmupOperatorOpts) {
   const dephyNcn = getDephyNcnProgram();
@@ -127,10 +462,231 @@ export async function warmupOperator(provider: AnchorProvider, opts: WarmupOpera
 
   const { signature, pubkeys } = await dephyNcn.methods
     .warmupOperator()
+    .accountsPartial({
+      config: configPubkey,
+      ncn: configAccount.ncn,
+      operator: operatorPubkey,
+      ...(await warmupAccounts(provider, configPubkey, operatorPubkey, opts.joinRequest)),
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface CooldownOperatorOpts {
+  config: string;
+  operator: string;
+}
+export async function cooldownOperator(provider: AnchorProvider, opts: CooldownOperatorOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const operatorPubkey = new web3.PublicKey(opts['operator'])
+
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .cooldownOperator()
     .accounts({
       config: configPubkey,
       ncn: configAccount.ncn,
       operator: operatorPubkey,
+      authority: provider.publicKey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface InitializeEpochSnapshotOpts {
+  config: string;
+}
+export async function initializeEpochSnapshot(provider: AnchorProvider, opts: InitializeEpochSnapshotOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+  const round = await currentRound(provider, configPubkey);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .initializeEpochSnapshot({
+      round: new BN(round),
+    })
+    .accounts({
+      config: configPubkey,
+      ncn: configAccount.ncn,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface SnapshotOperatorOpts {
+  config: string;
+  operator: string;
+  // every ncn vault, in ncn vault ticket index order
+  vault: string[];
+}
+export async function snapshotOperator(provider: AnchorProvider, opts: SnapshotOperatorOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const operatorPubkey = new web3.PublicKey(opts['operator']);
+  const vaultPubkeys = opts['vault'].map((vault) => new web3.PublicKey(vault));
+
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+  const epochSnapshot = findEpochSnapshot(configPubkey, await currentRound(provider, configPubkey));
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .snapshotOperator()
+    .accounts({
+      config: configPubkey,
+      epochSnapshot,
+      operator: operatorPubkey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  const signatures = [signature];
+  const operatorSnapshotAccount = await dephyNcn.account.operatorSnapshot.fetch(pubkeys.operatorSnapshot);
+  if (operatorSnapshotAccount.complete) {
+    return signatures
+  }
+
+  // one vault per transaction, a transaction can not hold the accounts of many vaults
+  for (const vaultPubkey of vaultPubkeys) {
+    signatures.push(await dephyNcn.methods
+      .snapshotOperatorVault()
+      .accountsPartial({
+        config: configPubkey,
+        epochSnapshot,
+        operatorSnapshot: pubkeys.operatorSnapshot,
+      })
+      .remainingAccounts(vaultStakeAccounts(configAccount.ncn, operatorPubkey, vaultPubkey))
+      .rpc());
+  }
+
+  return signatures
+}
+
+
+export interface UpdateConfigOpts {
+  config: string;
+  allowAdminVote?: boolean;
+  consensusThresholdBps?: number;
+  minVoters?: number;
+  minParticipationBps?: number;
+  // 0 for jito restaking epochs
+  votingPeriodSlots?: number;
+  ballotRetentionRounds?: number;
+  challengePeriodSlots?: number;
+  // comma separated pubkeys, empty string clears the guardians
+  guardians?: string;
+}
+export async function updateConfig(provider: AnchorProvider, opts: UpdateConfigOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .updateConfig({
+      allowAdminVote: opts.allowAdminVote ?? null,
+      consensusThresholdBps: opts.consensusThresholdBps ?? null,
+      minVoters: opts.minVoters !== undefined ? new BN(opts.minVoters) : null,
+      minParticipationBps: opts.minParticipationBps ?? null,
+      votingPeriod: opts.votingPeriodSlots === undefined ? null
+        : opts.votingPeriodSlots === 0 ? { jitoEpoch: {} }
+        : { slots: { length: new BN(opts.votingPeriodSlots) } },
+      ballotRetentionRounds: opts.ballotRetentionRounds !== undefined ? new BN(opts.ballotRetentionRounds) : null,
+      challengePeriodSlots: opts.challengePeriodSlots !== undefined ? new BN(opts.challengePeriodSlots) : null,
+      guardians: opts.guardians === undefined ? null
+        : opts.guardians.split(',').filter((g) => g).map((g) => new web3.PublicKey(g)),
+    })
+    .accounts({
+      config: configPubkey,
+      authority: provider.publicKey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface SetNcnAdminOpts {
+  config: string;
+  // admin, operatorAdmin, vaultAdmin, slasherAdmin, delegateAdmin, metadataAdmin, weightTableAdmin, ncnProgramAdmin
+  role: string;
+  newAdmin: string;
+}
+export async function setNcnAdmin(provider: AnchorProvider, opts: SetNcnAdminOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const newAdminPubkey = new web3.PublicKey(opts['newAdmin']);
+
+  const configAccount = await dephyNcn.account.config.fetch(configPubkey)
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .setNcnAdmin({
+      role: { [opts.role]: {} } as any,
+    })
+    .accounts({
+      config: configPubkey,
+      ncn: configAccount.ncn,
+      newAdmin: newAdminPubkey,
+      authority: provider.publicKey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface ProposeAuthorityOpts {
+  config: string;
+  newAuthority: string;
+}
+export async function proposeAuthority(provider: AnchorProvider, opts: ProposeAuthorityOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const newAuthorityPubkey = new web3.PublicKey(opts['newAuthority']);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .proposeAuthority()
+    .accounts({
+      config: configPubkey,
+      authority: provider.publicKey,
+      newAuthority: newAuthorityPubkey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface AcceptAuthorityOpts {
+  config: string;
+}
+export async function acceptAuthority(provider: AnchorProvider, opts: AcceptAuthorityOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .acceptAuthority()
+    .accounts({
+      config: configPubkey,
+      newAuthority: provider.publicKey,
     })
     .rpcAndKeys()
 
@@ -143,27 +699,186 @@ export async function warmupOperator(provider: AnchorProvider, opts: WarmupOpera
 export interface VoteOpts {
   config: string;
   operator: string;
-  vault: string;
   rewardsRoot: string;
+  // move the vote of the current round to `rewardsRoot`
+  revote?: boolean;
 }
 export async function vote(provider: AnchorProvider, opts: VoteOpts) {
   const dephyNcn = getDephyNcnProgram();
   const configPubkey = new web3.PublicKey(opts['config']);
   const operatorPubkey = new web3.PublicKey(opts['operator']);
-  const vaultPubkey = new web3.PublicKey(opts['vault']);
   const rewardsRoot = Buffer.from(opts['rewardsRoot'], 'hex')
 
   console.assert(rewardsRoot.length == 32, 'MerkleRoot shoule be 32 bytes')
 
+  const round = await currentRound(provider, configPubkey);
+
+  const method = opts.revote ? dephyNcn.methods.revote : dephyNcn.methods.vote;
+
+  const { signature, pubkeys } = await method({ proposedRewardsRoot: Array.from(rewardsRoot) })
+    .accounts({
+      config: configPubkey,
+      ballotBox: findBallotBox(configPubkey, round),
+      epochSnapshot: findEpochSnapshot(configPubkey, round),
+      voter: provider.publicKey,
+      operator: operatorPubkey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface CloseBallotBoxOpts {
+  config: string;
+  round: number;
+}
+export async function closeBallotBox(provider: AnchorProvider, opts: CloseBallotBoxOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const ballotBox = findBallotBox(configPubkey, opts.round);
+  const ballotBoxAccount = await dephyNcn.account.ballotBox.fetch(ballotBox);
+
   const { signature, pubkeys } = await dephyNcn.methods
-    .vote({
-      proposedRewardsRoot: Array.from(rewardsRoot)
+    .closeBallotBox()
+    .accountsPartial({
+      config: configPubkey,
+      epochSnapshot: findEpochSnapshot(configPubkey, opts.round),
+      ballotBox,
+      payer: ballotBoxAccount.payer,
     })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface CloseEpochSnapshotOpts {
+  config: string;
+  round: number;
+}
+export async function closeEpochSnapshot(provider: AnchorProvider, opts: CloseEpochSnapshotOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const epochSnapshot = findEpochSnapshot(configPubkey, opts.round);
+  const epochSnapshotAccount = await dephyNcn.account.epochSnapshot.fetch(epochSnapshot);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .closeEpochSnapshot()
+    .accountsPartial({
+      config: configPubkey,
+      epochSnapshot,
+      ballotBox: findBallotBox(configPubkey, opts.round),
+      payer: epochSnapshotAccount.payer,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface CloseOperatorSnapshotOpts {
+  config: string;
+  operator: string;
+  round: number;
+}
+export async function closeOperatorSnapshot(provider: AnchorProvider, opts: CloseOperatorSnapshotOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const operatorPubkey = new web3.PublicKey(opts['operator']);
+
+  const epochSnapshot = findEpochSnapshot(configPubkey, opts.round);
+  const [operatorSnapshot] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('operator_snapshot'), epochSnapshot.toBuffer(), operatorPubkey.toBuffer()],
+    dephyNcn.programId
+  );
+  const operatorSnapshotAccount = await dephyNcn.account.operatorSnapshot.fetch(operatorSnapshot);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .closeOperatorSnapshot()
+    .accountsPartial({
+      config: configPubkey,
+      epochSnapshot,
+      operatorSnapshot,
+      payer: operatorSnapshotAccount.payer,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+
+export interface VetoRoundOpts {
+  config: string;
+  round: number;
+}
+export async function vetoRound(provider: AnchorProvider, opts: VetoRoundOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .vetoRound()
     .accounts({
       config: configPubkey,
-      operatorAdmin: provider.publicKey,
-      operator: operatorPubkey,
-      vault: vaultPubkey,
+      ballotBox: findBallotBox(configPubkey, opts.round),
+      signer: provider.publicKey,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface FinalizeRoundOpts {
+  config: string;
+  round: number;
+}
+export async function finalizeRound(provider: AnchorProvider, opts: FinalizeRoundOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .finalizeRound()
+    .accounts({
+      config: configPubkey,
+      ballotBox: findBallotBox(configPubkey, opts.round),
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
+
+export interface PublishRewardsRootOpts {
+  config: string;
+  round: number;
+}
+export async function publishRewardsRoot(provider: AnchorProvider, opts: PublishRewardsRootOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+
+  const rewardsState = findRewardsState(configPubkey);
+  const rewardsStateAccount = await getDephyRewardsProgram().account.rewardsState.fetch(rewardsState);
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .publishRewardsRoot()
+    .accountsPartial({
+      config: configPubkey,
+      ballotBox: findBallotBox(configPubkey, opts.round),
+      rewardsState,
+      merkleRootVersion: findMerkleRootVersion(rewardsState, rewardsStateAccount.version.toNumber() + 1),
     })
     .rpcAndKeys()
 
@@ -172,3 +887,44 @@ export async function vote(provider: AnchorProvider, opts: VoteOpts) {
   return signature
 }
 
+
+export interface SettleOperatorRoundOpts {
+  config: string;
+  operator: string;
+  round: number;
+}
+export async function settleOperatorRound(provider: AnchorProvider, opts: SettleOperatorRoundOpts) {
+  const dephyNcn = getDephyNcnProgram();
+  const configPubkey = new web3.PublicKey(opts['config']);
+  const operatorPubkey = new web3.PublicKey(opts['operator']);
+
+  const epochSnapshot = findEpochSnapshot(configPubkey, opts.round);
+  const [operatorSnapshot] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('operator_snapshot'), epochSnapshot.toBuffer(), operatorPubkey.toBuffer()],
+    dephyNcn.programId
+  );
+  const [voterState] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('voter_state'), configPubkey.toBuffer(), operatorPubkey.toBuffer()],
+    dephyNcn.programId
+  );
+  const [offenceLedger] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('offence_ledger'), configPubkey.toBuffer(), operatorPubkey.toBuffer()],
+    dephyNcn.programId
+  );
+
+  const { signature, pubkeys } = await dephyNcn.methods
+    .settleOperatorRound()
+    .accountsPartial({
+      config: configPubkey,
+      epochSnapshot,
+      ballotBox: findBallotBox(configPubkey, opts.round),
+      operatorSnapshot,
+      voterState,
+      offenceLedger,
+    })
+    .rpcAndKeys()
+
+  debugPubkeys(pubkeys);
+
+  return signature
+}
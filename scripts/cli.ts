@@ -1,11 +1,33 @@
 import { Command } from '@commander-js/extra-typings';
 import {
+  acceptAuthority,
+  addRewardsMint,
+  closeBallotBox,
+  closeEpochSnapshot,
+  closeOperatorSnapshot,
+  cooldownOperator,
+  cooldownVault,
+  cooldownVaultSlasher,
+  finalizeRound,
+  initializeEpochSnapshot,
   initializeNcn,
   initializeOperator,
+  initializeRewards,
   initializeVault,
+  initializeVaultSlasher,
+  proposeAuthority,
+  publishRewardsRoot,
+  requestJoin,
+  reviewJoinRequest,
+  setNcnAdmin,
+  settleOperatorRound,
+  snapshotOperator,
+  updateConfig,
+  vetoRound,
   vote,
   warmupOperator,
   warmupVault,
+  warmupVaultSlasher,
 } from './actions';
 import { AnchorProvider } from '@coral-xyz/anchor';
 import { getProvider } from './common';
@@ -39,6 +61,7 @@ cli.command('initialize-vault')
   .description('Initialize vault connection')
   .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
   .requiredOption('-v, --vault <pubkey>', 'Vault account pubkey')
+  .option('--join-request', 'Onboard with the approved join request instead of the authority')
   .action(async (opts) => {
     const signature = await initializeVault(provider, opts);
     console.log('initialize-vault:', signature)
@@ -48,15 +71,74 @@ cli.command('warmup-vault')
   .description('Warmup vault connection')
   .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
   .requiredOption('-v, --vault <pubkey>', 'Vault account pubkey')
+  .option('--join-request', 'Onboard with the approved join request instead of the authority')
   .action(async (opts) => {
     const signature = await warmupVault(provider, opts);
     console.log('warmup-vault:', signature)
   });
 
+cli.command('initialize-vault-slasher')
+  .description('Register Dephy NCN as a slasher of a vault')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-v, --vault <pubkey>', 'Vault account pubkey')
+  .requiredOption('--max-slashable-per-epoch <amount>', 'Max amount slashable per epoch', Number)
+  .action(async (opts) => {
+    const signature = await initializeVaultSlasher(provider, opts);
+    console.log('initialize-vault-slasher:', signature)
+  });
+
+cli.command('warmup-vault-slasher')
+  .description('Warmup vault slasher registration')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-v, --vault <pubkey>', 'Vault account pubkey')
+  .action(async (opts) => {
+    const signature = await warmupVaultSlasher(provider, opts);
+    console.log('warmup-vault-slasher:', signature)
+  });
+
+cli.command('cooldown-vault')
+  .description('Cooldown vault connection')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-v, --vault <pubkey>', 'Vault account pubkey')
+  .action(async (opts) => {
+    const signature = await cooldownVault(provider, opts);
+    console.log('cooldown-vault:', signature)
+  });
+
+cli.command('cooldown-vault-slasher')
+  .description('Cooldown vault slasher registration')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-v, --vault <pubkey>', 'Vault account pubkey')
+  .action(async (opts) => {
+    const signature = await cooldownVaultSlasher(provider, opts);
+    console.log('cooldown-vault-slasher:', signature)
+  });
+
+cli.command('request-join')
+  .description('Ask to onboard a vault or operator, signed by its admin')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('--kind <kind>', 'vault or operator')
+  .requiredOption('-t, --target <pubkey>', 'Vault or operator account pubkey')
+  .action(async (opts) => {
+    const signature = await requestJoin(provider, { ...opts, kind: opts.kind as 'vault' | 'operator' });
+    console.log('request-join:', signature)
+  });
+
+cli.command('review-join-request')
+  .description('Approve a join request, or reject and close it')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-t, --target <pubkey>', 'Vault or operator account pubkey')
+  .option('--reject', 'Reject the join request')
+  .action(async (opts) => {
+    const signature = await reviewJoinRequest(provider, opts);
+    console.log('review-join-request:', signature)
+  });
+
 cli.command('initialize-operator')
   .description('Initialize operator connection')
   .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
   .requiredOption('-o, --operator <pubkey>', 'Operator account pubkey')
+  .option('--join-request', 'Onboard with the approved join request instead of the authority')
   .action(async (opts) => {
     const signature = await initializeOperator(provider, opts);
     console.log('initialize-operator:', signature)
@@ -66,20 +148,174 @@ cli.command('warmup-operator')
   .description('Warmup operator connection')
   .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
   .requiredOption('-o, --operator <pubkey>', 'Operator account pubkey')
+  .option('--join-request', 'Onboard with the approved join request instead of the authority')
   .action(async (opts) => {
     const signature = await warmupOperator(provider, opts);
     console.log('warmup-operator:', signature)
   });
 
+cli.command('cooldown-operator')
+  .description('Cooldown operator connection')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-o, --operator <pubkey>', 'Operator account pubkey')
+  .action(async (opts) => {
+    const signature = await cooldownOperator(provider, opts);
+    console.log('cooldown-operator:', signature)
+  });
+
+cli.command('initialize-epoch-snapshot')
+  .description('Initialize stake snapshot of current epoch')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .action(async (opts) => {
+    const signature = await initializeEpochSnapshot(provider, opts);
+    console.log('initialize-epoch-snapshot:', signature)
+  });
+
+cli.command('snapshot-operator')
+  .description('Add operator stake to snapshot of current epoch, one transaction per vault')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-o, --operator <pubkey>', 'Operator account pubkey')
+  .requiredOption('-v, --vault <pubkey...>', 'All NCN vault pubkeys in ticket index order')
+  .action(async (opts) => {
+    const signature = await snapshotOperator(provider, opts);
+    console.log('snapshot-operator:', signature)
+  });
+
+cli.command('update-config')
+  .description('Update Dephy NCN config')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .option('--allow-admin-vote <bool>', 'Accept votes signed by operator admin', (v) => v === 'true')
+  .option('--consensus-threshold-bps <bps>', 'Share of snapshot stake a rewards root needs', Number)
+  .option('--min-voters <count>', 'Operators that must have voted for a rewards root', Number)
+  .option('--min-participation-bps <bps>', 'Share of snapshot stake that must have voted', Number)
+  .option('--voting-period-slots <slots>', 'Length of a voting round in slots, 0 for jito restaking epochs', Number)
+  .option('--ballot-retention-rounds <rounds>', 'Rounds a ballot box is kept before it can be closed', Number)
+  .option('--challenge-period-slots <slots>', 'Slots a consensus root can be vetoed before it is final', Number)
+  .option('--guardians <pubkeys>', 'Comma separated pubkeys that can veto rounds, empty to clear')
+  .action(async (opts) => {
+    const signature = await updateConfig(provider, opts);
+    console.log('update-config:', signature)
+  });
+
+cli.command('set-ncn-admin')
+  .description('Assign a jito ncn admin role, the admin role hands over the whole ncn')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('--role <role>', 'admin, operatorAdmin, vaultAdmin, slasherAdmin, delegateAdmin, metadataAdmin, weightTableAdmin or ncnProgramAdmin')
+  .requiredOption('--new-admin <pubkey>', 'New admin pubkey, must be the cli keypair for the admin role')
+  .action(async (opts) => {
+    const signature = await setNcnAdmin(provider, opts);
+    console.log('set-ncn-admin:', signature)
+  });
+
+cli.command('propose-authority')
+  .description('Propose a new Dephy NCN config authority')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-n, --new-authority <pubkey>', 'New authority pubkey, the current one cancels')
+  .action(async (opts) => {
+    const signature = await proposeAuthority(provider, opts);
+    console.log('propose-authority:', signature)
+  });
+
+cli.command('accept-authority')
+  .description('Accept the Dephy NCN config authority with the cli keypair')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .action(async (opts) => {
+    const signature = await acceptAuthority(provider, opts);
+    console.log('accept-authority:', signature)
+  });
+
 cli.command('vote')
   .description('Submit vote for rewards distribution')
   .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
   .requiredOption('-o, --operator <pubkey>', 'Operator account pubkey')
-  .requiredOption('-v, --vault <pubkey>', 'Vault account pubkey')
   .requiredOption('--rewards-root <hash>', 'Rewards root hash in hex string')
+  .option('--revote', 'Change the vote of the current round before consensus')
   .action(async (opts) => {
     const signature = await vote(provider, opts);
     console.log('vote:', signature)
   });
 
+cli.command('close-ballot-box')
+  .description('Close a ballot box after its retention period, once all operators of the round are settled')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('--round <round>', 'Voting round of the ballot box', Number)
+  .action(async (opts) => {
+    const signature = await closeBallotBox(provider, opts);
+    console.log('close-ballot-box:', signature)
+  });
+
+cli.command('close-epoch-snapshot')
+  .description('Close an epoch snapshot after its retention period, once its ballot box is closed')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('--round <round>', 'Voting round of the epoch snapshot', Number)
+  .action(async (opts) => {
+    const signature = await closeEpochSnapshot(provider, opts);
+    console.log('close-epoch-snapshot:', signature)
+  });
+
+cli.command('close-operator-snapshot')
+  .description('Close a settled operator snapshot after its retention period')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-o, --operator <pubkey>', 'Operator account pubkey')
+  .requiredOption('--round <round>', 'Voting round of the operator snapshot', Number)
+  .action(async (opts) => {
+    const signature = await closeOperatorSnapshot(provider, opts);
+    console.log('close-operator-snapshot:', signature)
+  });
+
+cli.command('initialize-rewards')
+  .description('Initialize the rewards state owned by Dephy NCN')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-m, --rewards-mint <pubkey>', 'Rewards token mint')
+  .action(async (opts) => {
+    const signature = await initializeRewards(provider, opts);
+    console.log('initialize-rewards:', signature)
+  });
+
+cli.command('add-rewards-mint')
+  .description('Add an extra rewards mint to the rewards state owned by Dephy NCN')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-m, --rewards-mint <pubkey>', 'Extra rewards token mint')
+  .action(async (opts) => {
+    const signature = await addRewardsMint(provider, opts);
+    console.log('add-rewards-mint:', signature)
+  });
+
+cli.command('veto-round')
+  .description('Veto the consensus root of a round in its challenge period, as authority or guardian')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('--round <round>', 'Voting round of the ballot box', Number)
+  .action(async (opts) => {
+    const signature = await vetoRound(provider, opts);
+    console.log('veto-round:', signature)
+  });
+
+cli.command('finalize-round')
+  .description('Finalize the consensus root of a round after its challenge period')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('--round <round>', 'Voting round of the ballot box', Number)
+  .action(async (opts) => {
+    const signature = await finalizeRound(provider, opts);
+    console.log('finalize-round:', signature)
+  });
+
+cli.command('publish-rewards-root')
+  .description('Publish the consensus rewards root of a round to the rewards state')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('--round <round>', 'Voting round of the ballot box', Number)
+  .action(async (opts) => {
+    const signature = await publishRewardsRoot(provider, opts);
+    console.log('publish-rewards-root:', signature)
+  });
+
+cli.command('settle-operator-round')
+  .description('Update operator performance and offences of a past round')
+  .requiredOption('-c, --config <pubkey>', 'Dephy NCN Config account pubkey')
+  .requiredOption('-o, --operator <pubkey>', 'Operator account pubkey')
+  .requiredOption('--round <round>', 'Voting round to settle', Number)
+  .action(async (opts) => {
+    const signature = await settleOperatorRound(provider, opts);
+    console.log('settle-operator-round:', signature)
+  });
+
 cli.parseAsync(process.argv).catch(console.error);
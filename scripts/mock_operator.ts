@@ -1,4 +1,4 @@
-import { vote } from './actions';
+import { currentRound, findBallotBox, vote } from './actions';
 import { Command } from '@commander-js/extra-typings';
 import * as anchor from '@coral-xyz/anchor';
 import { web3 } from '@coral-xyz/anchor';
@@ -10,26 +10,17 @@ import { getProvider } from './common';
 const cli = new Command();
 let dephyNcn: Program<DephyNcn>
 
-async function fetchBallotBox(configPubkey: web3.PublicKey, programId: web3.PublicKey) {
-  const [ballotBoxPda] = web3.PublicKey.findProgramAddressSync(
-    [Buffer.from('ballot_box'), configPubkey.toBuffer()],
-    programId
-  );
-  return await dephyNcn.account.ballotBox.fetch(ballotBoxPda);
-}
-
-function calcMockedRoot(epoch: number) {
-  return Buffer.from(keccak_256.digest(`MOCKED_ROOT:${epoch}`));
+function calcMockedRoot(round: number) {
+  return Buffer.from(keccak_256.digest(`MOCKED_ROOT:${round}`));
 }
 
 cli
   .name('mock-operator')
   .description('Operator CLI for voting')
-  .requiredOption('-k, --keypair <path>', 'Operator admin keypair JSON file path')
+  .requiredOption('-k, --keypair <path>', 'Operator voter keypair JSON file path')
   .requiredOption('-r, --rpc <url>', 'Solana RPC URL')
   .requiredOption('--config <pubkey>', 'Config public key')
   .requiredOption('--operator <pubkey>', 'Operator public key')
-  .requiredOption('--vault <pubkey>', 'Vault account pubkey')
   .option('-p, --program-id <programId>', 'DephyNCN Program ID')
   .option('--interval <seconds>', 'Polling interval in seconds', '600')
   .action(async (opts) => {
@@ -39,11 +30,9 @@ cli
     const programId = opts.programId ? new web3.PublicKey(opts.programId) : dephyNcn.programId;
     const configPubkey = new web3.PublicKey(opts.config);
     const operatorPubkey = new web3.PublicKey(opts.operator);
-    const vaultPubkey = new web3.PublicKey(opts.vault);
 
     // TODO:
-    // operator admin should match
-    // vault operator ticket should match
+    // operator voter should match
     // ncn operator state should match
 
     const [voterStateAddress] = web3.PublicKey.findProgramAddressSync(
@@ -52,35 +41,42 @@ cli
     );
 
     const voterState = await dephyNcn.account.voterState.fetch(voterStateAddress);
-    let lastVotedEpoch = voterState.lastVotedEpoch.toNumber();
-    console.log(`Last voted at epoch ${lastVotedEpoch}`);
+    let lastVotedRound = voterState.lastVotedRound.toNumber();
+    console.log(`Last voted at round ${lastVotedRound}`);
+
+    dephyNcn.addEventListener('consensusReached', (event) => {
+      if (event.config.equals(configPubkey)) {
+        console.log(`Consensus reached for round ${event.round.toString()}`, Buffer.from(event.rewardsRoot).toString('hex'));
+      }
+    });
 
     while (true) {
-      const { epoch } = await provider.connection.getEpochInfo();
-      if (epoch > lastVotedEpoch) {
+      const round = await currentRound(provider, configPubkey);
+      if (round > lastVotedRound) {
         try {
-          console.log(`Detected new epoch: ${epoch}, voting...`);
-          const mockedRoot = calcMockedRoot(epoch)
-          const ballotBox = await fetchBallotBox(configPubkey, programId);
+          console.log(`Detected new round: ${round}, voting...`);
+          const mockedRoot = calcMockedRoot(round)
+          const ballotBox = await dephyNcn.account.ballotBox.fetchNullable(findBallotBox(configPubkey, round));
 
-          const currentRewardsRoot = Buffer.from(ballotBox.proposedRewardsRoot)
-          if (ballotBox.epoch.eqn(epoch)) {
-            if (!currentRewardsRoot.equals(mockedRoot)) {
-              console.error('RewardsRoot not match', currentRewardsRoot, mockedRoot)
+          if (ballotBox) {
+            for (const ballot of ballotBox.ballots) {
+              const proposedRoot = Buffer.from(ballot.rewardsRoot)
+              if (!proposedRoot.equals(mockedRoot)) {
+                console.error('RewardsRoot not match', proposedRoot, mockedRoot, 'stake', ballot.stake.toString())
+              }
             }
           } else {
-            console.log('no one has voted yet')
+            console.log('epoch snapshot not initialized yet')
           }
 
           const rewardsRoot = mockedRoot.toString('hex')
           await vote(provider, {
             config: opts.config,
             operator: opts.operator,
-            vault: opts.vault,
             rewardsRoot,
           });
-          lastVotedEpoch = epoch;
-          console.log(`Voted for epoch ${epoch} ${rewardsRoot}`);
+          lastVotedRound = round;
+          console.log(`Voted for round ${round} ${rewardsRoot}`);
 
         } catch (e) {
           console.error(`Vote failed:`, e);
@@ -4,11 +4,12 @@ import { AnchorProvider, Program, BN, web3 } from '@coral-xyz/anchor';
 import * as spl from '@solana/spl-token';
 import { DephyRewards } from '../target/types/dephy_rewards';
 import { readFileSync } from 'fs';
-import { buildRewardsTree } from "../tests/rewards-tree";
+import { buildRewardsTree, RewardsNode } from "../tests/rewards-tree";
 import path from "path";
 import os from "os";
 import { getProvider } from './common';
-import { getClaimRewardsInstructionDataDecoder, hashNode, MerkleTree } from '../clients/dephy-rewards/js/src';
+import { findMerkleRootVersion } from './actions';
+import { getClaimForMessage, getClaimRewardsInstructionDataDecoder, hashNode, MerkleTree } from '../clients/dephy-rewards/js/src';
 import * as gill from 'gill'
 
 
@@ -17,12 +18,52 @@ const cli = new Command();
 let provider: AnchorProvider;
 let dephyRewards: Program<DephyRewards>;
 
+// entry of a rewards file, `vesting` holds unix timestamps
+type RewardsEntry = {
+  user: string;
+  // unset for the primary rewards mint
+  mint?: string;
+  amount: string | number;
+  vesting?: { startTs: string | number; cliffTs: string | number; endTs: string | number };
+}
+
+const toRewardsNode = ({ user, mint, amount, vesting }: RewardsEntry): RewardsNode => ({
+  user: new web3.PublicKey(user),
+  mint: mint && new web3.PublicKey(mint),
+  amount: BigInt(amount),
+  vesting: vesting && {
+    startTs: BigInt(vesting.startTs),
+    cliffTs: BigInt(vesting.cliffTs),
+    endTs: BigInt(vesting.endTs),
+  },
+})
+
+const vestingArg = ({ vesting }: RewardsNode) => vesting ? {
+  startTs: new BN(vesting.startTs.toString()),
+  cliffTs: new BN(vesting.cliffTs.toString()),
+  endTs: new BN(vesting.endTs.toString()),
+} : null
+
+const findRewardsNode = (nodes: RewardsNode[], user: web3.PublicKey, mint: web3.PublicKey, primaryMint: web3.PublicKey) =>
+  nodes.findIndex((node) => node.user.equals(user) && (node.mint ?? primaryMint).equals(mint))
+
+// token account of the rewards vault for a mint, `rewardsTokenAccount` for the primary one
+const findRewardsTokenAccount = (rewardsStatePubkey: web3.PublicKey, mint: web3.PublicKey, tokenProgram: web3.PublicKey) => {
+  const [rewardsVault] = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from('rewards_vault'), rewardsStatePubkey.toBuffer()],
+    dephyRewards.programId
+  )
+  return spl.getAssociatedTokenAddressSync(mint, rewardsVault, true, tokenProgram)
+}
+
 const logRewardsState = (rewardsState: Awaited<ReturnType<typeof dephyRewards.account.rewardsState.all>>[number]) => {
   console.log('Rewards State:', rewardsState.publicKey.toString())
   console.log('Authority:', rewardsState.account.authority.toString())
   console.log('Rewards Mint:', rewardsState.account.rewardsMint.toString())
   console.log('Rewards Token Account:', rewardsState.account.rewardsTokenAccount.toString())
+  console.log('Extra Rewards Mints:', rewardsState.account.extraRewardsMints.map((mint) => mint.toString()))
   console.log('Merkle Root:', rewardsState.account.merkleRoot)
+  console.log('Version:', rewardsState.account.version.toString())
 }
 
 
@@ -66,6 +107,70 @@ cli
   });
 
 
+cli.command('propose-admin')
+  .description('Propose a new global config admin')
+  .requiredOption('-n, --new-admin <pubkey>', 'New admin account pubkey')
+  .action(async (opts) => {
+    try {
+      const newAdminPubkey = new web3.PublicKey(opts.newAdmin);
+
+      const tx = await dephyRewards.methods
+        .proposeAdmin()
+        .accounts({
+          admin: provider.publicKey,
+          newAdmin: newAdminPubkey,
+        })
+        .signers([provider.wallet.payer])
+        .rpc();
+
+      console.log('Admin proposed');
+      console.log('Transaction signature:', tx);
+    } catch (err) {
+      console.error('Failed to propose admin:', err);
+    }
+  });
+
+
+cli.command('accept-admin')
+  .description('Accept the global config admin role with the cli keypair')
+  .action(async () => {
+    try {
+      const tx = await dephyRewards.methods
+        .acceptAdmin()
+        .accounts({
+          newAdmin: provider.publicKey,
+        })
+        .signers([provider.wallet.payer])
+        .rpc();
+
+      console.log('Admin accepted');
+      console.log('Transaction signature:', tx);
+    } catch (err) {
+      console.error('Failed to accept admin:', err);
+    }
+  });
+
+
+cli.command('migrate-global-config')
+  .description('Grow a global config created by an older program to the current layout')
+  .action(async () => {
+    try {
+      const tx = await dephyRewards.methods
+        .migrateGlobalConfig()
+        .accounts({
+          admin: provider.publicKey,
+          payer: provider.publicKey,
+        })
+        .rpc();
+
+      console.log('Global config migrated');
+      console.log('Transaction signature:', tx);
+    } catch (err) {
+      console.error('Failed to migrate global config:', err);
+    }
+  });
+
+
 cli.command('initialize-rewards-state')
   .description('Initialize the rewards state')
   .requiredOption('-m, --mint <pubkey>', 'Rewards mint account pubkey')
@@ -96,6 +201,56 @@ cli.command('initialize-rewards-state')
     }
   });
 
+cli.command('migrate-rewards-state')
+  .description('Grow a rewards state created by an older program to the current layout')
+  .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
+  .action(async (opts) => {
+    try {
+      const tx = await dephyRewards.methods
+        .migrateRewardsState()
+        .accounts({
+          rewardsState: new web3.PublicKey(opts.state),
+          authority: provider.publicKey,
+          payer: provider.publicKey,
+        })
+        .rpc();
+
+      console.log('Rewards state migrated');
+      console.log('Transaction signature:', tx);
+    } catch (err) {
+      console.error('Failed to migrate rewards state:', err);
+    }
+  });
+
+cli.command('add-rewards-mint')
+  .description('Pay rewards in another mint as well')
+  .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
+  .requiredOption('-m, --mint <pubkey>', 'Rewards mint account pubkey')
+  .action(async (opts) => {
+    try {
+      const rewardsStatePubkey = new web3.PublicKey(opts.state);
+      const mintPubkey = new web3.PublicKey(opts.mint);
+      const mintAccount = await provider.connection.getAccountInfo(mintPubkey);
+
+      const tx = await dephyRewards.methods
+        .addRewardsMint()
+        .accountsPartial({
+          rewardsState: rewardsStatePubkey,
+          authority: provider.publicKey,
+          rewardsMint: mintPubkey,
+          rewardsTokenAccount: findRewardsTokenAccount(rewardsStatePubkey, mintPubkey, mintAccount.owner),
+          payer: provider.publicKey,
+          rewardsTokenProgram: mintAccount.owner,
+        })
+        .rpc();
+
+      console.log('Rewards mint added');
+      console.log('Transaction signature:', tx);
+    } catch (err) {
+      console.error('Failed to add rewards mint:', err);
+    }
+  });
+
 cli.command('all-rewards-states')
   .description('Get all rewards states')
   .action(async () => {
@@ -153,6 +308,8 @@ cli.command('update-merkle-root')
   .description('Update the merkle root for rewards distribution')
   .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
   .requiredOption('--root <hash>', 'Merkle root hash as a hex string')
+  .option('--total-amount <amount>', 'Sum of the rewards in the tree', '0')
+  .option('--leaf-count <count>', 'Leaves in the tree', Number, 0)
   .action(async (opts) => {
     try {
       const statePubkey = new web3.PublicKey(opts.state);
@@ -160,6 +317,7 @@ cli.command('update-merkle-root')
       if (merkleRoot.length !== 32) {
         throw new Error('Merkle root must be 32 bytes (64 hex chars)')
       }
+      const rewardsState = await dephyRewards.account.rewardsState.fetch(statePubkey);
 
       const tx = await dephyRewards.methods
         .updateMerkleRoot({
@@ -167,11 +325,14 @@ cli.command('update-merkle-root')
             inplace: {
               hash: Array.from(merkleRoot)
             }
-          }
+          },
+          totalAmount: new BN(opts.totalAmount),
+          leafCount: opts.leafCount,
         })
-        .accounts({
+        .accountsPartial({
           rewardsState: statePubkey,
           authority: provider.publicKey,
+          merkleRootVersion: findMerkleRootVersion(statePubkey, rewardsState.version.toNumber() + 1),
         })
         .rpc();
 
@@ -187,11 +348,14 @@ cli.command('update-merkle-root-external')
   .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
   .requiredOption('--address <pubkey>', 'External merkle root pubkey')
   .requiredOption('--offset <offset>', 'External merkle root offset')
+  .option('--total-amount <amount>', 'Sum of the rewards in the tree', '0')
+  .option('--leaf-count <count>', 'Leaves in the tree', Number, 0)
   .action(async (opts) => {
     try {
       const statePubkey = new web3.PublicKey(opts.state);
       const externalPubkey = new web3.PublicKey(opts.address);
       const externalOffset = new BN(opts.offset);
+      const rewardsState = await dephyRewards.account.rewardsState.fetch(statePubkey);
 
       const tx = await dephyRewards.methods
         .updateMerkleRoot({
@@ -200,11 +364,14 @@ cli.command('update-merkle-root-external')
               pubkey: externalPubkey,
               offset: externalOffset,
             }
-          }
+          },
+          totalAmount: new BN(opts.totalAmount),
+          leafCount: opts.leafCount,
         })
-        .accounts({
+        .accountsPartial({
           rewardsState: statePubkey,
           authority: provider.publicKey,
+          merkleRootVersion: findMerkleRootVersion(statePubkey, rewardsState.version.toNumber() + 1),
         })
         .rpc();
 
@@ -216,24 +383,50 @@ cli.command('update-merkle-root-external')
   });
 
 
+cli.command('list-merkle-roots')
+  .description('List every merkle root version of a rewards state')
+  .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
+  .action(async (opts) => {
+    try {
+      const versions = await dephyRewards.account.merkleRootVersion.all([
+        { memcmp: { offset: 8, bytes: opts.state } },
+      ]);
+      versions.sort((a, b) => a.account.version.cmp(b.account.version));
+      for (const { account } of versions) {
+        console.log(
+          'Version:', account.version.toString(),
+          'Slot:', account.slot.toString(),
+          'Total Amount:', account.totalAmount.toString(),
+          'Leaf Count:', account.leafCount,
+          'Merkle Root:', JSON.stringify(account.merkleRoot),
+        );
+      }
+    } catch (err) {
+      console.error('Failed to list merkle roots:', err);
+    }
+  });
+
+
 cli.command('fund-rewards')
   .description('Fund rewards')
   .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
   .requiredOption('-a, --amount <amount>', 'Amount to fund')
+  .option('-m, --mint <pubkey>', 'Rewards mint, the primary one by default')
   .action(async (opts) => {
     try {
       const rewardsStatePubkey = new web3.PublicKey(opts.state);
       const amount = new BN(opts.amount);
       const rewardsState = await dephyRewards.account.rewardsState.fetch(rewardsStatePubkey);
-      const rewardsMintAccount = await provider.connection.getAccountInfo(rewardsState.rewardsMint)
+      const mint = opts.mint ? new web3.PublicKey(opts.mint) : mint;
+      const rewardsMintAccount = await provider.connection.getAccountInfo(mint)
       const rewardsMint = await spl.getMint(
         provider.connection,
-        rewardsState.rewardsMint,
+        mint,
         undefined,
         rewardsMintAccount.owner,
       )
       const sourceAccount = spl.getAssociatedTokenAddressSync(
-        rewardsState.rewardsMint,
+        mint,
         provider.publicKey,
         true,
         rewardsMintAccount.owner,
@@ -243,8 +436,8 @@ cli.command('fund-rewards')
         provider.connection,
         provider.wallet.payer,
         sourceAccount,
-        rewardsState.rewardsMint,
-        rewardsState.rewardsTokenAccount,
+        mint,
+        findRewardsTokenAccount(rewardsStatePubkey, mint, rewardsMintAccount.owner),
         provider.publicKey,
         amount.toNumber(),
         rewardsMint.decimals,
@@ -266,41 +459,48 @@ cli.command('claim-rewards')
   .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
   .requiredOption('--rewards <path>', 'Path to rewards file')
   .option('-b, --beneficiary <pubkey>', 'Beneficiary account pubkey')
+  .option('--version <version>', 'Claim against a recent previous merkle root version', Number)
+  .option('-m, --mint <pubkey>', 'Rewards mint, the primary one by default')
   .action(async (opts) => {
     try {
       const rewardsStatePubkey = new web3.PublicKey(opts.state);
       const beneficiary = opts.beneficiary ? new web3.PublicKey(opts.beneficiary) : provider.publicKey;
-      const rewards = JSON.parse(readFileSync(opts.rewards, 'utf8'));
-      const rewardsNodes: Parameters<typeof buildRewardsTree>[0] = rewards.map(({ user, amount }) => ({
-        user: new web3.PublicKey(user),
-        amount: BigInt(amount)
-      }));
+      const rewards: RewardsEntry[] = JSON.parse(readFileSync(opts.rewards, 'utf8'));
+      const rewardsNodes = rewards.map(toRewardsNode);
       if (rewardsNodes.length == 0) {
         console.error('No rewards found');
         process.exit(1)
       }
 
+      const rewardsState = await dephyRewards.account.rewardsState.fetch(rewardsStatePubkey);
+      const mint = opts.mint ? new web3.PublicKey(opts.mint) : rewardsState.rewardsMint;
+
       const rewardsTree = buildRewardsTree(rewardsNodes);
-      const index = rewardsNodes.findIndex(({ user }) => user.equals(provider.publicKey));
+      const index = findRewardsNode(rewardsNodes, provider.publicKey, mint, rewardsState.rewardsMint);
       const proof = rewardsTree.getProof(index).proof.map(b => Array.from(b));
       const { user, amount } = rewardsNodes[index]
+      const vesting = vestingArg(rewardsNodes[index])
       console.log('rewards', user.toString(), amount)
       const totalRewards = new BN(amount.toString())
 
-      const rewardsState = await dephyRewards.account.rewardsState.fetch(rewardsStatePubkey);
-      const maybeMerkleRootAccount = rewardsState.merkleRoot.external ? rewardsState.merkleRoot.external.pubkey : null;
+      const merkleRootVersion = opts.version !== undefined ? findMerkleRootVersion(rewardsStatePubkey, opts.version) : null;
+      const merkleRoot = merkleRootVersion
+        ? (await dephyRewards.account.merkleRootVersion.fetch(merkleRootVersion)).merkleRoot
+        : rewardsState.merkleRoot;
+      const maybeMerkleRootAccount = merkleRoot.external ? merkleRoot.external.pubkey : null;
 
-      const rewardsMint = await provider.connection.getAccountInfo(rewardsState.rewardsMint)
+      const rewardsMint = await provider.connection.getAccountInfo(mint)
+      const rewardsTokenAccountPubkey = findRewardsTokenAccount(rewardsStatePubkey, mint, rewardsMint.owner)
       const rewardsTokenAccount = await spl.getAccount(
         provider.connection,
-        rewardsState.rewardsTokenAccount,
+        rewardsTokenAccountPubkey,
         undefined,
         rewardsMint.owner,
       )
       console.log('rewardsTokenAccount', rewardsTokenAccount.address.toString(), rewardsTokenAccount.amount)
 
       const beneficiaryTokenAccountPubkey = spl.getAssociatedTokenAddressSync(
-        rewardsState.rewardsMint,
+        mint,
         beneficiary,
         true,
         rewardsMint.owner,
@@ -312,7 +512,7 @@ cli.command('claim-rewards')
         await spl.createAssociatedTokenAccountIdempotent(
           provider.connection,
           provider.wallet.payer,
-          rewardsState.rewardsMint,
+          mint,
           beneficiary,
           undefined,
           rewardsMint.owner
@@ -323,15 +523,18 @@ cli.command('claim-rewards')
         .claimRewards({
           index,
           totalRewards,
-          proof
+          proof,
+          vesting,
         })
-        .accounts({
+        .accountsPartial({
           rewardsState: rewardsStatePubkey,
-          rewardsMint: rewardsState.rewardsMint,
-          rewardsTokenAccount: rewardsState.rewardsTokenAccount,
+          rewardsMint: mint,
+          rewardsTokenAccount: rewardsTokenAccountPubkey,
           owner: user,
+          claimState: findClaimState(rewardsStatePubkey, user, mint, rewardsState.rewardsMint),
           beneficiaryTokenAccount: beneficiaryTokenAccountPubkey,
           maybeMerkleRootAccount,
+          merkleRootVersion,
           payer: provider.publicKey,
           rewardsTokenProgram: rewardsMint.owner,
         })
@@ -344,16 +547,133 @@ cli.command('claim-rewards')
     }
   });
 
+// claim states of the primary mint have no mint seed
+const findClaimState = (rewardsStatePubkey: web3.PublicKey, owner: web3.PublicKey, mint: web3.PublicKey, primaryMint: web3.PublicKey) => web3.PublicKey.findProgramAddressSync(
+  [Buffer.from('claim_state'), rewardsStatePubkey.toBuffer(), owner.toBuffer(), ...(mint.equals(primaryMint) ? [] : [mint.toBuffer()])],
+  dephyRewards.programId
+)[0]
+
+// owner signed message of a relayed claim, nonce from the claim state
+const claimForMessage = async (rewardsStatePubkey: web3.PublicKey, owner: web3.PublicKey, totalRewards: bigint, beneficiaryTokenAccount: web3.PublicKey) => {
+  const { mint } = await spl.getAccount(provider.connection, beneficiaryTokenAccount, undefined,
+    (await provider.connection.getAccountInfo(beneficiaryTokenAccount)).owner)
+  const { rewardsMint } = await dephyRewards.account.rewardsState.fetch(rewardsStatePubkey)
+  const claimState = await dephyRewards.account.claimState.fetchNullable(findClaimState(rewardsStatePubkey, owner, mint, rewardsMint));
+  const nonce = claimState ? BigInt(claimState.nonce.toString()) : 0n;
+  return {
+    nonce,
+    message: getClaimForMessage(
+      gill.address(rewardsStatePubkey.toBase58()),
+      totalRewards,
+      gill.address(beneficiaryTokenAccount.toBase58()),
+      nonce,
+    ),
+  }
+}
+
+cli.command('sign-claim')
+  .description('Sign a claim with the cli keypair as owner, for a relayer to submit')
+  .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
+  .requiredOption('--total-rewards <amount>', 'Total rewards of the owner in the rewards tree')
+  .requiredOption('--beneficiary-token-account <pubkey>', 'Token account receiving the rewards')
+  .action(async (opts) => {
+    try {
+      const { nonce, message } = await claimForMessage(
+        new web3.PublicKey(opts.state),
+        provider.publicKey,
+        BigInt(opts.totalRewards),
+        new web3.PublicKey(opts.beneficiaryTokenAccount),
+      );
+      const keyPair = await gill.createKeyPairFromBytes(provider.wallet.payer.secretKey);
+      const signature = await gill.signBytes(keyPair.privateKey, message);
+
+      console.log('Nonce:', nonce.toString());
+      console.log('Signature:', Buffer.from(signature).toString('hex'));
+    } catch (err) {
+      console.error('Failed to sign claim:', err);
+    }
+  });
+
+cli.command('claim-rewards-for')
+  .description('Submit and pay for a claim signed by its owner')
+  .requiredOption('-s, --state <pubkey>', 'Rewards state account pubkey')
+  .requiredOption('--rewards <path>', 'Path to rewards file')
+  .requiredOption('--owner <pubkey>', 'Owner of the rewards')
+  .requiredOption('--beneficiary-token-account <pubkey>', 'Token account receiving the rewards')
+  .requiredOption('--signature <hex>', 'Owner signature from sign-claim')
+  .action(async (opts) => {
+    try {
+      const rewardsStatePubkey = new web3.PublicKey(opts.state);
+      const owner = new web3.PublicKey(opts.owner);
+      const beneficiaryTokenAccount = new web3.PublicKey(opts.beneficiaryTokenAccount);
+      const rewards: RewardsEntry[] = JSON.parse(readFileSync(opts.rewards, 'utf8'));
+      const rewardsNodes = rewards.map(toRewardsNode);
+
+      const rewardsState = await dephyRewards.account.rewardsState.fetch(rewardsStatePubkey);
+      // the beneficiary token account picks the mint
+      const beneficiaryAccount = await provider.connection.getAccountInfo(beneficiaryTokenAccount)
+      const { mint } = await spl.getAccount(provider.connection, beneficiaryTokenAccount, undefined, beneficiaryAccount.owner);
+      const mintAccount = await provider.connection.getAccountInfo(mint)
+
+      const rewardsTree = buildRewardsTree(rewardsNodes);
+      const index = findRewardsNode(rewardsNodes, owner, mint, rewardsState.rewardsMint);
+      if (index < 0) {
+        console.error('No rewards found for owner');
+        process.exit(1)
+      }
+      const proof = rewardsTree.getProof(index).proof.map(b => Array.from(b));
+      const { amount } = rewardsNodes[index]
+      const vesting = vestingArg(rewardsNodes[index])
+
+      const maybeMerkleRootAccount = rewardsState.merkleRoot.external ? rewardsState.merkleRoot.external.pubkey : null;
+
+      const { nonce, message } = await claimForMessage(rewardsStatePubkey, owner, amount, beneficiaryTokenAccount);
+
+      const tx = await dephyRewards.methods
+        .claimRewardsFor({
+          claim: {
+            index,
+            totalRewards: new BN(amount.toString()),
+            proof,
+            vesting,
+          },
+          nonce: new BN(nonce.toString()),
+        })
+        .accountsPartial({
+          rewardsState: rewardsStatePubkey,
+          rewardsMint: mint,
+          rewardsTokenAccount: findRewardsTokenAccount(rewardsStatePubkey, mint, mintAccount.owner),
+          owner,
+          claimState: findClaimState(rewardsStatePubkey, owner, mint, rewardsState.rewardsMint),
+          beneficiaryTokenAccount,
+          maybeMerkleRootAccount,
+          merkleRootVersion: null,
+          payer: provider.publicKey,
+          rewardsTokenProgram: mintAccount.owner,
+        })
+        .preInstructions([
+          web3.Ed25519Program.createInstructionWithPublicKey({
+            publicKey: owner.toBytes(),
+            message,
+            signature: Buffer.from(opts.signature, 'hex'),
+          }),
+        ])
+        .rpc();
+
+      console.log('Rewards claimed successfully');
+      console.log('Transaction signature:', tx);
+    } catch (err) {
+      console.error('Failed to claim rewards:', err);
+    }
+  });
+
 cli.command('calc-rewards-root')
   .description('Calculate the rewards root hash')
   .requiredOption('--rewards <path>', 'Path to rewards file')
   .action(async (opts) => {
     try {
-      const rewards = JSON.parse(readFileSync(opts.rewards, 'utf8'));
-      const rewardsNodes = rewards.map(({ user, amount }) => ({
-        user: new web3.PublicKey(user),
-        amount: BigInt(amount)
-      }));
+      const rewards: RewardsEntry[] = JSON.parse(readFileSync(opts.rewards, 'utf8'));
+      const rewardsNodes = rewards.map(toRewardsNode);
       if (rewardsNodes.length == 0) {
         console.error('No rewards found');
         process.exit(1)
@@ -380,15 +700,17 @@ cli.command('check-proof-data')
 
       console.dir(decodedData, { depth: null })
 
+      const { index, totalRewards, proof, vesting } = decodedData.args
       const leaf = hashNode({
         user: ownerPubkey,
-        amount: decodedData.totalRewards,
+        amount: totalRewards,
+        vesting: gill.unwrapOption(vesting) ?? undefined,
       })
 
       const computedRoot = MerkleTree.hashProof({
         leaf,
-        leafIndex: decodedData.index,
-        proof: decodedData.proof.map(p => Buffer.from(p)),
+        leafIndex: index,
+        proof: proof.map(p => Buffer.from(p)),
         root: null
       })
 
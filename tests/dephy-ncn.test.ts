@@ -6,6 +6,8 @@ import * as spl from '@solana/spl-token';
 import { assert } from 'chai';
 import { readFileSync } from 'fs';
 import { $ } from 'bun';
+import { currentRound, findBallotBox, vaultStakeAccounts } from '../scripts/actions';
+import { buildRewardsTree } from './rewards-tree';
 
 
 const debugPubkeys = (pubkeys) => {
@@ -69,21 +71,11 @@ describe("dephy-ncn", () => {
 
   const authority = web3.Keypair.generate()
   const rewardsMintKeypair = web3.Keypair.generate()
-  const rewardsStateKeypair = web3.Keypair.generate()
+  let rewardsStatePubkey: web3.PublicKey
 
   let vaultPubkey: web3.PublicKey
   const vaultMintKeypair = web3.Keypair.generate()
 
-  const [rewardsVault, _rewardsVaultBump] = web3.PublicKey.findProgramAddressSync(
-    [Buffer.from("rewards_vault"), rewardsStateKeypair.publicKey.toBuffer()],
-    dephyRewards.programId
-  );
-  
-  const rewardsTokenAccount = spl.getAssociatedTokenAddressSync(
-    rewardsMintKeypair.publicKey,
-    rewardsVault,
-    true
-  )
 
   before(async () => {
     provider.connection.requestAirdrop(jitoAdminKeypair.publicKey, web3.LAMPORTS_PER_SOL * 10)
@@ -163,22 +155,99 @@ describe("dephy-ncn", () => {
 
     const ncnAdmin = await provider.connection.getAccountInfo(pubkeys.ncnAdmin);
     assert.isNull(ncnAdmin)
+  })
 
-    ballotBoxPubkey = pubkeys.ballotBox;
 
-    const ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
-    assert(ballotBox.config.equals(configPubkey));
+  it("set ncn secondary admin", async () => {
+    const metadataAdmin = web3.Keypair.generate().publicKey
+    const [ncnAdmin] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("ncn_admin"), ncnPubkey.toBuffer()],
+      dephyNcn.programId
+    );
+
+    await dephyNcn.methods
+      .setNcnAdmin({ role: { metadataAdmin: {} } })
+      .accounts({
+        config: configPubkey,
+        ncn: ncnPubkey,
+        newAdmin: metadataAdmin,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    // discriminator, base, admin, operator_admin, vault_admin, slasher_admin, delegate_admin, metadata_admin
+    const { data } = await provider.connection.getAccountInfo(ncnPubkey);
+    assert(new web3.PublicKey(data.subarray(8 + 32, 8 + 32 * 2)).equals(ncnAdmin));
+    assert(new web3.PublicKey(data.subarray(8 + 32 * 6, 8 + 32 * 7)).equals(metadataAdmin));
+  })
+
+  it("ncn admin role needs the new admin to sign", async () => {
+    try {
+      await dephyNcn.methods
+        .setNcnAdmin({ role: { admin: {} } })
+        .accounts({
+          config: configPubkey,
+          ncn: ncnPubkey,
+          newAdmin: web3.Keypair.generate().publicKey,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      assert.fail("handed over the ncn without the new admin signature");
+    } catch (error) {
+      assert.include(error.message, "InvalidAuthority");
+    }
+  })
+
+
+  it("initialize rewards", async () => {
+    [rewardsStatePubkey] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rewards_state"), configPubkey.toBuffer()],
+      dephyNcn.programId
+    );
+    const [rewardsVault] = web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("rewards_vault"), rewardsStatePubkey.toBuffer()],
+      dephyRewards.programId
+    );
+    const rewardsTokenAccount = spl.getAssociatedTokenAddressSync(
+      rewardsMintKeypair.publicKey,
+      rewardsVault,
+      true
+    )
+
+    await dephyNcn.methods
+      .initializeRewards()
+      .accountsPartial({
+        config: configPubkey,
+        rewardsState: rewardsStatePubkey,
+        rewardsVault,
+        rewardsMint: rewardsMintKeypair.publicKey,
+        rewardsTokenAccount,
+        authority: authority.publicKey,
+        rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+
+    const rewardsState = await dephyRewards.account.rewardsState.fetch(rewardsStatePubkey);
+    assert(rewardsState.authority.equals(configPubkey));
+    assert(rewardsState.rewardsMint.equals(rewardsMintKeypair.publicKey));
+    assert(rewardsState.rewardsTokenAccount.equals(rewardsTokenAccount));
   })
 
 
   it("connect vault", async () => {
     const tx = dephyNcn.methods
       .initializeVault()
-      .accounts({
+      .accountsPartial({
         config: configPubkey,
         ncn: ncnPubkey,
         vault: vaultPubkey,
+        authority: authority.publicKey,
+        joinRequest: null,
       })
+      .signers([authority])
 
     const pubkeys = await tx.pubkeys();
     debugPubkeys(pubkeys);
@@ -193,11 +262,15 @@ describe("dephy-ncn", () => {
 
     await dephyNcn.methods
       .warmupVault()
-      .accounts({
+      .accountsPartial({
         config: configPubkey,
         ncn: ncnPubkey,
         vault: vaultPubkey,
+        authority: authority.publicKey,
+        joinRequest: null,
+        joinRequestPayer: null,
       })
+      .signers([authority])
       .rpc()
   })
 
@@ -235,11 +308,14 @@ describe("dephy-ncn", () => {
   it("connect operator 0", async () => {
     const tx = dephyNcn.methods
       .initializeOperator()
-      .accounts({
+      .accountsPartial({
         config: configPubkey,
         ncn: ncnPubkey,
         operator: op0Pubkey,
+        authority: authority.publicKey,
+        joinRequest: null,
       })
+      .signers([authority])
     
     const pubkeys = await tx.pubkeys()
     debugPubkeys(pubkeys)
@@ -254,24 +330,74 @@ describe("dephy-ncn", () => {
 
     await dephyNcn.methods
       .warmupOperator()
-      .accounts({
+      .accountsPartial({
         config: configPubkey,
         ncn: ncnPubkey,
         operator: op0Pubkey,
+        authority: authority.publicKey,
+        joinRequest: null,
+        joinRequestPayer: null,
       })
+      .signers([authority])
       .rpc()
 
     // operator warmup
     await $`${jitoCliOp0} restaking operator operator-warmup-ncn ${op0Pubkey} ${ncnPubkey}`
   })
 
-  it("connect operator 1", async () => {
+  it("connect operator 1 by join request", async () => {
+    const requestTx = dephyNcn.methods
+      .requestJoin({ kind: { operator: {} } })
+      .accounts({
+        config: configPubkey,
+        target: op1Pubkey,
+        applicant: op1AdminKeypair.publicKey,
+      })
+      .signers([op1AdminKeypair])
+
+    const { joinRequest } = await requestTx.pubkeys()
+    await requestTx.rpc()
+
+    // not approved yet
+    try {
+      await dephyNcn.methods
+        .initializeOperator()
+        .accountsPartial({
+          config: configPubkey,
+          ncn: ncnPubkey,
+          operator: op1Pubkey,
+          authority: null,
+          joinRequest,
+        })
+        .rpc()
+      assert.fail("onboarded without approval")
+    } catch (error) {
+      assert.include(error.message, "OnboardingNotAllowed")
+    }
+
+    await dephyNcn.methods
+      .reviewJoinRequest({ approve: true })
+      .accounts({
+        config: configPubkey,
+        joinRequest,
+        authority: authority.publicKey,
+        payer: provider.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+
+    const joinRequestAccount = await dephyNcn.account.joinRequest.fetch(joinRequest)
+    assert(joinRequestAccount.approved)
+    assert(joinRequestAccount.applicant.equals(op1AdminKeypair.publicKey))
+
     const tx = dephyNcn.methods
       .initializeOperator()
-      .accounts({
+      .accountsPartial({
         config: configPubkey,
         ncn: ncnPubkey,
         operator: op1Pubkey,
+        authority: null,
+        joinRequest,
       })
     
     const pubkeys = await tx.pubkeys()
@@ -287,13 +413,19 @@ describe("dephy-ncn", () => {
 
     await dephyNcn.methods
       .warmupOperator()
-      .accounts({
+      .accountsPartial({
         config: configPubkey,
         ncn: ncnPubkey,
         operator: op1Pubkey,
+        authority: null,
+        joinRequest,
+        joinRequestPayer: provider.publicKey,
       })
       .rpc()
 
+    // the used join request is closed
+    assert.isNull(await provider.connection.getAccountInfo(joinRequest))
+
     // operator warmup
     await $`${jitoCliOp1} restaking operator operator-warmup-ncn ${op1Pubkey} ${ncnPubkey}`
   })
@@ -327,33 +459,274 @@ describe("dephy-ncn", () => {
     await $`${jitoCliVaultAdmin} vault vault delegate-to-operator ${vaultPubkey} ${op1Pubkey} 1000000000`
   })
 
-  const proposedRewardsRoot = new Uint8Array(32)
-  crypto.getRandomValues(proposedRewardsRoot)
+  // a real rewards tree, so it can be claimed against the ballot box
+  const ncnRewardsUser = web3.Keypair.generate()
+  const ncnRewards = [
+    { user: ncnRewardsUser.publicKey, amount: 1000000n },
+    { user: web3.Keypair.generate().publicKey, amount: 2000000n },
+  ]
+  const ncnRewardsTree = buildRewardsTree(ncnRewards)
+  const proposedRewardsRoot = new Uint8Array(ncnRewardsTree.getRoot())
+
+  const noConfigUpdate = {
+    allowAdminVote: null,
+    consensusThresholdBps: null,
+    minVoters: null,
+    minParticipationBps: null,
+    votingPeriod: null,
+    ballotRetentionRounds: null,
+    challengePeriodSlots: null,
+    guardians: null,
+  }
+
+  // registers the operator, then adds the stake of the only ncn vault
+  const snapshotOperator = async (epochSnapshot: web3.PublicKey, operator: web3.PublicKey) => {
+    const tx = dephyNcn.methods
+      .snapshotOperator()
+      .accounts({
+        config: configPubkey,
+        epochSnapshot,
+        operator,
+      })
 
-  it("op0 vote", async () => {
+    const { operatorSnapshot } = await tx.pubkeys();
+    await tx.rpc();
+
+    const snapshotVault = () => dephyNcn.methods
+      .snapshotOperatorVault()
+      .accountsPartial({
+        config: configPubkey,
+        epochSnapshot,
+        operatorSnapshot,
+      })
+      .remainingAccounts(vaultStakeAccounts(ncnPubkey, operator, vaultPubkey))
+      .rpc();
+
+    return { operatorSnapshot, snapshotVault }
+  }
+
+  // epoch snapshot and ballot box of the current round, with both operators registered
+  const snapshotRound = async () => {
+    const round = await currentRound(provider, configPubkey);
+    const tx = dephyNcn.methods
+      .initializeEpochSnapshot({ round: new anchor.BN(round) })
+      .accounts({
+        config: configPubkey,
+        ncn: ncnPubkey,
+      })
+
+    const { epochSnapshot } = await tx.pubkeys();
+    await tx.rpc();
+
+    for (const operator of [op0Pubkey, op1Pubkey]) {
+      const { snapshotVault } = await snapshotOperator(epochSnapshot, operator);
+      await snapshotVault();
+    }
+
+    return { round, epochSnapshot, ballotBox: findBallotBox(configPubkey, round) }
+  }
+
+  const castVote = (
+    { epochSnapshot, ballotBox }: { epochSnapshot: web3.PublicKey, ballotBox: web3.PublicKey },
+    voter: web3.Keypair,
+    operator: web3.PublicKey,
+    rewardsRoot: Uint8Array,
+  ) => dephyNcn.methods
+    .vote({
+      proposedRewardsRoot: Array.from(rewardsRoot),
+    })
+    .accounts({
+      config: configPubkey,
+      ballotBox,
+      epochSnapshot,
+      voter: voter.publicKey,
+      operator,
+    })
+    .signers([voter])
+    .rpc();
+
+  let epochSnapshotPubkey: web3.PublicKey
+
+  it("snapshot round stake", async () => {
+    // tickets and ncn operator states are active after a full warmup epoch
+    await nextEpoch();
     await nextEpoch();
 
+    const round = await currentRound(provider, configPubkey);
+    const tx = dephyNcn.methods
+      .initializeEpochSnapshot({ round: new anchor.BN(round) })
+      .accounts({
+        config: configPubkey,
+        ncn: ncnPubkey,
+      })
+
+    epochSnapshotPubkey = (await tx.pubkeys()).epochSnapshot;
+    await tx.rpc();
+
+    ballotBoxPubkey = findBallotBox(configPubkey, round);
+    const ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
+    assert(ballotBox.config.equals(configPubkey));
+    assert.equal(ballotBox.round.toNumber(), round);
+
+    for (const operator of [op0Pubkey, op1Pubkey]) {
+      const { operatorSnapshot, snapshotVault } = await snapshotOperator(epochSnapshotPubkey, operator);
+
+      // registered once every ncn vault is added
+      assert.isFalse((await dephyNcn.account.operatorSnapshot.fetch(operatorSnapshot)).complete);
+      await snapshotVault();
+      assert((await dephyNcn.account.operatorSnapshot.fetch(operatorSnapshot)).complete);
+
+      try {
+        await snapshotVault();
+        assert.fail("added a vault twice");
+      } catch (error) {
+        assert.include(error.message, "OperatorSnapshotComplete");
+      }
+    }
+
+    const epochSnapshot = await dephyNcn.account.epochSnapshot.fetch(epochSnapshotPubkey);
+    assert.equal(epochSnapshot.operatorsRegistered.toNumber(), 2);
+    assert.equal(epochSnapshot.totalStake.toNumber(), 1234567890);
+  })
+
+  it("register as vault slasher", async () => {
+    // ncn vault ticket is active by now
+    const tx = dephyNcn.methods
+      .initializeVaultSlasher({ maxSlashablePerEpoch: new anchor.BN(1000000) })
+      .accounts({
+        config: configPubkey,
+        ncn: ncnPubkey,
+        vault: vaultPubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+
+    const pubkeys = await tx.pubkeys();
+    await tx.rpc()
+
+    const slasherTicketAccount = await provider.connection.getAccountInfo(pubkeys.ncnVaultSlasherTicket)
+    assert(slasherTicketAccount.owner.equals(JITO_RESTAKING_ID))
+
+    // warmup need another slot
+    await Bun.sleep(400)
+
+    const other = web3.Keypair.generate()
+    try {
+      await dephyNcn.methods
+        .warmupVaultSlasher()
+        .accounts({
+          config: configPubkey,
+          ncn: ncnPubkey,
+          vault: vaultPubkey,
+          authority: other.publicKey,
+        })
+        .signers([other])
+        .rpc();
+      assert.fail("warmed up vault slasher without the authority");
+    } catch (error) {
+      assert.include(error.message, "InvalidAuthority");
+    }
+
+    await dephyNcn.methods
+      .warmupVaultSlasher()
+      .accounts({
+        config: configPubkey,
+        ncn: ncnPubkey,
+        vault: vaultPubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+  })
+
+  it("disable challenge period", async () => {
+    await dephyNcn.methods
+      .updateConfig({
+        allowAdminVote: null,
+        consensusThresholdBps: null,
+        minVoters: null,
+        minParticipationBps: null,
+        votingPeriod: null,
+        ballotRetentionRounds: null,
+        challengePeriodSlots: new anchor.BN(0),
+        guardians: null,
+      })
+      .accounts({
+        config: configPubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    const config = await dephyNcn.account.config.fetch(configPubkey);
+    assert.equal(config.challengePeriodSlots.toNumber(), 0);
+  })
+
+  it("op0 vote", async () => {
+
     const tx = dephyNcn.methods
       .vote({
         proposedRewardsRoot: Array.from(proposedRewardsRoot),
       })
       .accounts({
         config: configPubkey,
-        operatorAdmin: op0AdminKeypair.publicKey,
+        ballotBox: ballotBoxPubkey,
+        epochSnapshot: epochSnapshotPubkey,
+        voter: op0AdminKeypair.publicKey,
         operator: op0Pubkey,
-        vault: vaultPubkey,
       })
       .signers([op0AdminKeypair])
 
-    debugPubkeys(await tx.pubkeys());
+    const pubkeys = await tx.pubkeys();
+    debugPubkeys(pubkeys);
 
     await tx.rpc();
 
+    const voterState = await dephyNcn.account.voterState.fetch(pubkeys.voterState);
+    assert.equal(voterState.roundsParticipated.toNumber(), 1);
+    assert.equal(voterState.currentStreak.toNumber(), 1);
+    assert.equal(voterState.lastStake.toNumber(), 234567890);
+
     const ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
     assert.equal(ballotBox.operatorsVoted.toNumber(), 1);
-    assert.equal(ballotBox.approvedVotes.toNumber(), 234567890);
+    assert.equal(ballotBox.ballots.length, 1);
+    assert.equal(ballotBox.ballots[0].stake.toNumber(), 234567890);
     assert.deepEqual(ballotBox.rewardsRoot, new Array(32).fill(0));
-    assert.deepEqual(ballotBox.proposedRewardsRoot, Array.from(proposedRewardsRoot));
+    assert.deepEqual(ballotBox.ballots[0].rewardsRoot, Array.from(proposedRewardsRoot));
+  })
+
+  it("op0 revote", async () => {
+    const otherRewardsRoot = new Uint8Array(32)
+    crypto.getRandomValues(otherRewardsRoot)
+
+    const revote = (rewardsRoot: Uint8Array) => dephyNcn.methods
+      .revote({
+        proposedRewardsRoot: Array.from(rewardsRoot),
+      })
+      .accounts({
+        config: configPubkey,
+        ballotBox: ballotBoxPubkey,
+        epochSnapshot: epochSnapshotPubkey,
+        voter: op0AdminKeypair.publicKey,
+        operator: op0Pubkey,
+      })
+      .signers([op0AdminKeypair])
+      .rpc();
+
+    await revote(otherRewardsRoot);
+
+    let ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
+    assert.equal(ballotBox.operatorsVoted.toNumber(), 1);
+    assert.equal(ballotBox.ballots.length, 1);
+    assert.deepEqual(ballotBox.ballots[0].rewardsRoot, Array.from(otherRewardsRoot));
+
+    // back to the root op1 votes for
+    await revote(proposedRewardsRoot);
+
+    ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
+    assert.equal(ballotBox.ballots.length, 1);
+    assert.equal(ballotBox.ballots[0].stake.toNumber(), 234567890);
+    assert.deepEqual(ballotBox.ballots[0].rewardsRoot, Array.from(proposedRewardsRoot));
   })
 
   it("op1 vote", async () => {
@@ -363,9 +736,10 @@ describe("dephy-ncn", () => {
       })
       .accounts({
         config: configPubkey,
-        operatorAdmin: op1AdminKeypair.publicKey,
+        ballotBox: ballotBoxPubkey,
+        epochSnapshot: epochSnapshotPubkey,
+        voter: op1AdminKeypair.publicKey,
         operator: op1Pubkey,
-        vault: vaultPubkey,
       })
       .signers([op1AdminKeypair])
 
@@ -375,8 +749,798 @@ describe("dephy-ncn", () => {
 
     const ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
     assert.equal(ballotBox.operatorsVoted.toNumber(), 2);
-    assert.equal(ballotBox.approvedVotes.toNumber(), 1234567890);
-    assert(ballotBox.lastConsensusEpoch.eq(ballotBox.epoch));
+    assert.equal(ballotBox.ballots.length, 1);
+    assert.equal(ballotBox.ballots[0].stake.toNumber(), 1234567890);
+    assert.deepEqual(ballotBox.status, { reached: {} });
     assert.deepEqual(ballotBox.rewardsRoot, Array.from(proposedRewardsRoot));
   })
+
+  it("finalize round", async () => {
+    await dephyNcn.methods
+      .finalizeRound()
+      .accounts({
+        config: configPubkey,
+        ballotBox: ballotBoxPubkey,
+      })
+      .rpc();
+
+    const ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
+    assert.deepEqual(ballotBox.status, { finalized: {} });
+    assert(ballotBox.finalizedSlot.gtn(0));
+  })
+
+  it("publish rewards root", async () => {
+    await dephyNcn.methods
+      .publishRewardsRoot()
+      .accountsPartial({
+        config: configPubkey,
+        ballotBox: ballotBoxPubkey,
+        rewardsState: rewardsStatePubkey,
+        merkleRootVersion: web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("merkle_root_version"), rewardsStatePubkey.toBuffer(), new anchor.BN(1).toArrayLike(Buffer, "le", 8)],
+          dephyRewards.programId
+        )[0],
+      })
+      .rpc();
+
+    const rewardsState = await dephyRewards.account.rewardsState.fetch(rewardsStatePubkey);
+    assert.deepEqual(Array.from(rewardsState.merkleRoot.inplace.hash), Array.from(proposedRewardsRoot));
+    assert(rewardsState.version.eqn(1));
+
+    const config = await dephyNcn.account.config.fetch(configPubkey);
+    const ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
+    assert(config.lastPublishedRound.eq(ballotBox.round));
+  })
+
+  // a dephy-rewards state reading its root from the ncn ballot boxes
+  const ncnModeRewardsStateKeypair = web3.Keypair.generate()
+  let ncnModeRootVersion = 0
+  let ncnModeRewardsTokenAccount: web3.PublicKey
+  let ncnRewardsUserTokenAccount: web3.PublicKey
+
+  const setNcnModeRoot = (config: web3.PublicKey, round: number) => {
+    ncnModeRootVersion += 1
+    return dephyRewards.methods
+      .updateMerkleRoot({
+        merkleRoot: {
+          ncnBallotBox: { config, round: new anchor.BN(round) }
+        },
+        totalAmount: new anchor.BN(3000000),
+        leafCount: ncnRewards.length,
+      })
+      .accountsPartial({
+        rewardsState: ncnModeRewardsStateKeypair.publicKey,
+        authority: authority.publicKey,
+        merkleRootVersion: web3.PublicKey.findProgramAddressSync(
+          [Buffer.from("merkle_root_version"), ncnModeRewardsStateKeypair.publicKey.toBuffer(), new anchor.BN(ncnModeRootVersion).toArrayLike(Buffer, "le", 8)],
+          dephyRewards.programId
+        )[0],
+      })
+      .signers([authority])
+      .rpc();
+  }
+
+  const claimAgainst = (merkleRootAccount: web3.PublicKey) => dephyRewards.methods
+    .claimRewards({
+      index: 0,
+      totalRewards: new anchor.BN(ncnRewards[0].amount.toString()),
+      proof: ncnRewardsTree.getProof(0).proof.map(b => Array.from(b)),
+      vesting: null,
+    })
+    .accountsPartial({
+      rewardsState: ncnModeRewardsStateKeypair.publicKey,
+      rewardsMint: rewardsMintKeypair.publicKey,
+      rewardsTokenAccount: ncnModeRewardsTokenAccount,
+      owner: ncnRewardsUser.publicKey,
+      claimState: web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("claim_state"), ncnModeRewardsStateKeypair.publicKey.toBuffer(), ncnRewardsUser.publicKey.toBuffer()],
+        dephyRewards.programId
+      )[0],
+      beneficiaryTokenAccount: ncnRewardsUserTokenAccount,
+      maybeMerkleRootAccount: merkleRootAccount,
+      merkleRootVersion: null,
+      payer: provider.wallet.publicKey,
+      rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
+    })
+    .signers([ncnRewardsUser])
+    .rpc();
+
+  const assertClaimRejected = async (merkleRootAccount: web3.PublicKey, expectedError: string) => {
+    try {
+      await claimAgainst(merkleRootAccount);
+      assert.fail(`claimed, expected ${expectedError}`);
+    } catch (error) {
+      assert.include(error.message, expectedError);
+    }
+  }
+
+  it("claim against a finalized ncn ballot box", async () => {
+    await dephyRewards.methods
+      .initializeRewardsState()
+      .accounts({
+        rewardsState: ncnModeRewardsStateKeypair.publicKey,
+        authority: authority.publicKey,
+        rewardsMint: rewardsMintKeypair.publicKey,
+        payer: provider.wallet.publicKey,
+        rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
+      })
+      .signers([ncnModeRewardsStateKeypair, authority])
+      .rpc();
+
+    const rewardsState = await dephyRewards.account.rewardsState.fetch(ncnModeRewardsStateKeypair.publicKey);
+    ncnModeRewardsTokenAccount = rewardsState.rewardsTokenAccount;
+    await spl.mintTo(
+      provider.connection,
+      provider.wallet.payer,
+      rewardsMintKeypair.publicKey,
+      ncnModeRewardsTokenAccount,
+      authority,
+      3000000,
+    );
+    ncnRewardsUserTokenAccount = await spl.createAssociatedTokenAccountIdempotent(
+      provider.connection,
+      provider.wallet.payer,
+      rewardsMintKeypair.publicKey,
+      ncnRewardsUser.publicKey,
+    )
+
+    const ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
+    await setNcnModeRoot(configPubkey, ballotBox.round.toNumber());
+    await claimAgainst(ballotBoxPubkey);
+
+    const tokenAccount = await spl.getAccount(provider.connection, ncnRewardsUserTokenAccount);
+    assert.equal(tokenAccount.amount, ncnRewards[0].amount);
+  })
+
+  it("reject ncn ballot boxes of another config, round or program", async () => {
+    const ballotBox = await dephyNcn.account.ballotBox.fetch(ballotBoxPubkey);
+    const round = ballotBox.round.toNumber();
+
+    await setNcnModeRoot(web3.Keypair.generate().publicKey, round);
+    await assertClaimRejected(ballotBoxPubkey, "InvalidMerkleRootAccount");
+
+    await setNcnModeRoot(configPubkey, round + 1);
+    await assertClaimRejected(ballotBoxPubkey, "InvalidMerkleRootAccount");
+
+    await setNcnModeRoot(configPubkey, round);
+    // owned by dephy-ncn, not a ballot box
+    await assertClaimRejected(epochSnapshotPubkey, "InvalidMerkleRootAccount");
+    // not owned by dephy-ncn
+    await assertClaimRejected(ncnPubkey, "InvalidMerkleRootAccount");
+  })
+
+  let vetoedRound: Awaited<ReturnType<typeof snapshotRound>>
+
+  const guardian = web3.Keypair.generate()
+
+  const vetoRound = (ballotBox: web3.PublicKey, signer: web3.Keypair) => dephyNcn.methods
+    .vetoRound()
+    .accounts({
+      config: configPubkey,
+      ballotBox,
+      signer: signer.publicKey,
+    })
+    .signers([signer])
+    .rpc();
+
+  it("reject claims against an unfinalized ncn ballot box", async () => {
+    await dephyNcn.methods
+      .updateConfig({
+        ...noConfigUpdate,
+        challengePeriodSlots: new anchor.BN(1000000),
+        guardians: [guardian.publicKey],
+      })
+      .accounts({
+        config: configPubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    await nextEpoch();
+    vetoedRound = await snapshotRound();
+
+    // op1 alone has enough stake for consensus
+    await castVote(vetoedRound, op1AdminKeypair, op1Pubkey, proposedRewardsRoot);
+    const ballotBox = await dephyNcn.account.ballotBox.fetch(vetoedRound.ballotBox);
+    assert.deepEqual(ballotBox.status, { reached: {} });
+    assert(ballotBox.challengeEndSlot.eq(ballotBox.reachedSlot.addn(1000000)));
+
+    await setNcnModeRoot(configPubkey, vetoedRound.round);
+    await assertClaimRejected(vetoedRound.ballotBox, "ConsensusNotReached");
+  })
+
+  it("cannot finalize in the challenge period", async () => {
+    try {
+      await dephyNcn.methods
+        .finalizeRound()
+        .accounts({
+          config: configPubkey,
+          ballotBox: vetoedRound.ballotBox,
+        })
+        .rpc();
+      assert.fail("finalized in the challenge period");
+    } catch (error) {
+      assert.include(error.message, "ChallengePeriodNotOver");
+    }
+  })
+
+  it("veto round by a guardian", async () => {
+    try {
+      await vetoRound(vetoedRound.ballotBox, web3.Keypair.generate());
+      assert.fail("vetoed by neither the authority nor a guardian");
+    } catch (error) {
+      assert.include(error.message, "InvalidAuthority");
+    }
+
+    await vetoRound(vetoedRound.ballotBox, guardian);
+
+    const ballotBox = await dephyNcn.account.ballotBox.fetch(vetoedRound.ballotBox);
+    assert.deepEqual(ballotBox.status, { vetoed: {} });
+    assert.equal(ballotBox.finalizedSlot.toNumber(), 0);
+  })
+
+  it("reject claims against a vetoed ncn ballot box", async () => {
+    await assertClaimRejected(vetoedRound.ballotBox, "ConsensusNotReached");
+
+    try {
+      await dephyNcn.methods
+        .finalizeRound()
+        .accounts({
+          config: configPubkey,
+          ballotBox: vetoedRound.ballotBox,
+        })
+        .rpc();
+      assert.fail("finalized a vetoed round");
+    } catch (error) {
+      assert.include(error.message, "InvalidRoundStatus");
+    }
+  })
+
+  const findVoterState = (operator: web3.PublicKey) => web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("voter_state"), configPubkey.toBuffer(), operator.toBuffer()],
+    dephyNcn.programId
+  )[0];
+
+  const findOffenceLedger = (operator: web3.PublicKey) => web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("offence_ledger"), configPubkey.toBuffer(), operator.toBuffer()],
+    dephyNcn.programId
+  )[0];
+
+  const settleRound = (
+    { epochSnapshot, ballotBox }: { epochSnapshot: web3.PublicKey, ballotBox: web3.PublicKey },
+    operator: web3.PublicKey,
+  ) => dephyNcn.methods
+    .settleOperatorRound()
+    .accountsPartial({
+      config: configPubkey,
+      epochSnapshot,
+      ballotBox,
+      operatorSnapshot: web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("operator_snapshot"), epochSnapshot.toBuffer(), operator.toBuffer()],
+        dephyNcn.programId
+      )[0],
+      voterState: findVoterState(operator),
+      offenceLedger: findOffenceLedger(operator),
+    })
+    .rpc();
+
+  it("settle the finalized round", async () => {
+    const finalizedRound = { epochSnapshot: epochSnapshotPubkey, ballotBox: ballotBoxPubkey }
+    await settleRound(finalizedRound, op0Pubkey);
+    await settleRound(finalizedRound, op1Pubkey);
+
+    for (const operator of [op0Pubkey, op1Pubkey]) {
+      const voterState = await dephyNcn.account.voterState.fetch(findVoterState(operator));
+      assert.equal(voterState.roundsWon.toNumber(), 1);
+      assert.equal(voterState.roundsMissed.toNumber(), 0);
+      const offenceLedger = await dephyNcn.account.offenceLedger.fetch(findOffenceLedger(operator));
+      assert.equal(offenceLedger.offenceCount.toNumber(), 0);
+    }
+
+    const epochSnapshot = await dephyNcn.account.epochSnapshot.fetch(epochSnapshotPubkey);
+    assert.equal(epochSnapshot.operatorsSettled.toNumber(), 2);
+
+    try {
+      await settleRound(finalizedRound, op0Pubkey);
+      assert.fail("settled twice");
+    } catch (error) {
+      assert.include(error.message, "RoundAlreadySettled");
+    }
+  })
+
+  let losingRound: Awaited<ReturnType<typeof snapshotRound>>
+
+  it("vote against the consensus", async () => {
+    await dephyNcn.methods
+      .updateConfig({ ...noConfigUpdate, challengePeriodSlots: new anchor.BN(0) })
+      .accounts({
+        config: configPubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    await nextEpoch();
+    losingRound = await snapshotRound();
+
+    const otherRewardsRoot = new Uint8Array(32)
+    crypto.getRandomValues(otherRewardsRoot)
+    await castVote(losingRound, op0AdminKeypair, op0Pubkey, otherRewardsRoot);
+    await castVote(losingRound, op1AdminKeypair, op1Pubkey, proposedRewardsRoot);
+
+    // no challenge period left to veto in
+    try {
+      await vetoRound(losingRound.ballotBox, authority);
+      assert.fail("vetoed after the challenge period");
+    } catch (error) {
+      assert.include(error.message, "ChallengePeriodOver");
+    }
+
+    await dephyNcn.methods
+      .finalizeRound()
+      .accounts({
+        config: configPubkey,
+        ballotBox: losingRound.ballotBox,
+      })
+      .rpc();
+
+    const ballotBox = await dephyNcn.account.ballotBox.fetch(losingRound.ballotBox);
+    assert.deepEqual(ballotBox.status, { finalized: {} });
+    assert.deepEqual(ballotBox.rewardsRoot, Array.from(proposedRewardsRoot));
+  })
+
+  it("settle missed and losing votes", async () => {
+    // rounds are settled once over
+    await nextEpoch();
+
+    // op0 did not vote in the vetoed round, op1 did but nothing was finalized
+    await settleRound(vetoedRound, op0Pubkey);
+    await settleRound(vetoedRound, op1Pubkey);
+    await settleRound(losingRound, op0Pubkey);
+    await settleRound(losingRound, op1Pubkey);
+
+    const op0VoterState = await dephyNcn.account.voterState.fetch(findVoterState(op0Pubkey));
+    assert.equal(op0VoterState.roundsMissed.toNumber(), 1);
+    assert.equal(op0VoterState.roundsWon.toNumber(), 1);
+    // op0 voted again before its missed round was settled
+    assert.equal(op0VoterState.currentStreak.toNumber(), 1);
+
+    const op0OffenceLedger = await dephyNcn.account.offenceLedger.fetch(findOffenceLedger(op0Pubkey));
+    assert.equal(op0OffenceLedger.offenceCount.toNumber(), 2);
+    assert.equal(op0OffenceLedger.missedRounds.toNumber(), 1);
+    assert.equal(op0OffenceLedger.losingVotes.toNumber(), 1);
+    assert.deepEqual(op0OffenceLedger.offences[0].kind, { missedRound: {} });
+    assert.equal(op0OffenceLedger.offences[0].round.toNumber(), vetoedRound.round);
+    assert.deepEqual(op0OffenceLedger.offences[1].kind, { losingVote: {} });
+    assert.equal(op0OffenceLedger.offences[1].round.toNumber(), losingRound.round);
+    assert.equal(op0OffenceLedger.offences[1].stake.toNumber(), 234567890);
+
+    const op1VoterState = await dephyNcn.account.voterState.fetch(findVoterState(op1Pubkey));
+    assert.equal(op1VoterState.roundsMissed.toNumber(), 0);
+    assert.equal(op1VoterState.roundsWon.toNumber(), 2);
+
+    const op1OffenceLedger = await dephyNcn.account.offenceLedger.fetch(findOffenceLedger(op1Pubkey));
+    assert.equal(op1OffenceLedger.offenceCount.toNumber(), 0);
+  })
+
+  it("a missed round resets the streak", async () => {
+    await nextEpoch();
+    const roundInfo = await snapshotRound();
+    await castVote(roundInfo, op0AdminKeypair, op0Pubkey, proposedRewardsRoot);
+
+    let op1VoterState = await dephyNcn.account.voterState.fetch(findVoterState(op1Pubkey));
+    assert.isAbove(op1VoterState.currentStreak.toNumber(), 1);
+
+    await nextEpoch();
+    await settleRound(roundInfo, op1Pubkey);
+
+    op1VoterState = await dephyNcn.account.voterState.fetch(findVoterState(op1Pubkey));
+    assert.equal(op1VoterState.roundsMissed.toNumber(), 1);
+    assert.equal(op1VoterState.currentStreak.toNumber(), 0);
+    assert.isAbove(op1VoterState.longestStreak.toNumber(), 1);
+  })
+
+  const updateConfig = (update: Partial<typeof noConfigUpdate>, signer = authority) => dephyNcn.methods
+    .updateConfig({ ...noConfigUpdate, ...update })
+    .accounts({
+      config: configPubkey,
+      authority: signer.publicKey,
+    })
+    .signers([signer])
+    .rpc();
+
+  it("update config validation", async () => {
+    try {
+      await updateConfig({ minVoters: new anchor.BN(2) }, web3.Keypair.generate());
+      assert.fail("updated config without the authority");
+    } catch (error) {
+      assert.include(error.message, "InvalidAuthority");
+    }
+
+    const invalidUpdates = [
+      // half the stake could finalize two roots
+      { consensusThresholdBps: 5000 },
+      { consensusThresholdBps: 10001 },
+      { minVoters: new anchor.BN(0) },
+      { minParticipationBps: 10001 },
+    ]
+    for (const update of invalidUpdates) {
+      try {
+        await updateConfig(update);
+        assert.fail(`accepted ${JSON.stringify(update)}`);
+      } catch (error) {
+        assert.include(error.message, "InvalidConfig");
+      }
+    }
+  })
+
+  it("consensus needs min voters", async () => {
+    await updateConfig({ minVoters: new anchor.BN(2) });
+
+    await nextEpoch();
+    const roundInfo = await snapshotRound();
+
+    // op1 has enough stake, but votes alone
+    await castVote(roundInfo, op1AdminKeypair, op1Pubkey, proposedRewardsRoot);
+    let ballotBox = await dephyNcn.account.ballotBox.fetch(roundInfo.ballotBox);
+    assert.deepEqual(ballotBox.status, { voting: {} });
+
+    await castVote(roundInfo, op0AdminKeypair, op0Pubkey, proposedRewardsRoot);
+    ballotBox = await dephyNcn.account.ballotBox.fetch(roundInfo.ballotBox);
+    assert.deepEqual(ballotBox.status, { reached: {} });
+
+    await updateConfig({ minVoters: new anchor.BN(1) });
+  })
+
+  it("consensus needs the threshold", async () => {
+    await updateConfig({ consensusThresholdBps: 9000 });
+
+    await nextEpoch();
+    const roundInfo = await snapshotRound();
+
+    // 81% of the stake
+    await castVote(roundInfo, op1AdminKeypair, op1Pubkey, proposedRewardsRoot);
+    let ballotBox = await dephyNcn.account.ballotBox.fetch(roundInfo.ballotBox);
+    assert.deepEqual(ballotBox.status, { voting: {} });
+
+    await castVote(roundInfo, op0AdminKeypair, op0Pubkey, proposedRewardsRoot);
+    ballotBox = await dephyNcn.account.ballotBox.fetch(roundInfo.ballotBox);
+    assert.deepEqual(ballotBox.status, { reached: {} });
+
+    await updateConfig({ consensusThresholdBps: 6667 });
+  })
+
+  // never settled
+  let unsettledRound: Awaited<ReturnType<typeof snapshotRound>>
+
+  it("consensus needs min participation", async () => {
+    await updateConfig({ minParticipationBps: 10000 });
+
+    await nextEpoch();
+    const roundInfo = unsettledRound = await snapshotRound();
+
+    // above the threshold, but 19% of the stake has not voted
+    await castVote(roundInfo, op1AdminKeypair, op1Pubkey, proposedRewardsRoot);
+    let ballotBox = await dephyNcn.account.ballotBox.fetch(roundInfo.ballotBox);
+    assert.deepEqual(ballotBox.status, { voting: {} });
+
+    // a vote for another root still counts as participation
+    const otherRewardsRoot = new Uint8Array(32)
+    crypto.getRandomValues(otherRewardsRoot)
+    await castVote(roundInfo, op0AdminKeypair, op0Pubkey, otherRewardsRoot);
+    ballotBox = await dephyNcn.account.ballotBox.fetch(roundInfo.ballotBox);
+    assert.deepEqual(ballotBox.status, { reached: {} });
+    assert.deepEqual(Array.from(ballotBox.rewardsRoot), Array.from(proposedRewardsRoot));
+
+    await updateConfig({ minParticipationBps: 0 });
+  })
+
+  const closeBallotBox = ({ epochSnapshot, ballotBox }: { epochSnapshot: web3.PublicKey, ballotBox: web3.PublicKey }) => dephyNcn.methods
+    .closeBallotBox()
+    .accountsPartial({
+      config: configPubkey,
+      epochSnapshot,
+      ballotBox,
+      payer: provider.wallet.publicKey,
+    })
+    .rpc();
+
+  const closeEpochSnapshot = ({ epochSnapshot, ballotBox }: { epochSnapshot: web3.PublicKey, ballotBox: web3.PublicKey }) => dephyNcn.methods
+    .closeEpochSnapshot()
+    .accountsPartial({
+      config: configPubkey,
+      epochSnapshot,
+      ballotBox,
+      payer: provider.wallet.publicKey,
+    })
+    .rpc();
+
+  const findOperatorSnapshot = (epochSnapshot: web3.PublicKey, operator: web3.PublicKey) => web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("operator_snapshot"), epochSnapshot.toBuffer(), operator.toBuffer()],
+    dephyNcn.programId
+  )[0];
+
+  const closeOperatorSnapshot = ({ epochSnapshot }: { epochSnapshot: web3.PublicKey }, operator: web3.PublicKey) => dephyNcn.methods
+    .closeOperatorSnapshot()
+    .accountsPartial({
+      config: configPubkey,
+      epochSnapshot,
+      operatorSnapshot: findOperatorSnapshot(epochSnapshot, operator),
+      payer: provider.wallet.publicKey,
+    })
+    .rpc();
+
+  // lamports the recorded payer gets back from a close, net of the fee it paid for it
+  const refundOf = async (account: web3.PublicKey, close: () => Promise<string>) => {
+    const rent = await provider.connection.getBalance(account);
+    const before = await provider.connection.getBalance(provider.wallet.publicKey);
+    const signature = await close();
+    const { meta } = await provider.connection.getTransaction(signature, { commitment: "confirmed", maxSupportedTransactionVersion: 0 });
+    const after = await provider.connection.getBalance(provider.wallet.publicKey);
+
+    assert.isNull(await provider.connection.getAccountInfo(account));
+    return { rent, refund: after - before + meta.fee };
+  }
+
+  const assertCloseRejected = async (close: () => Promise<string>, code: string) => {
+    try {
+      await close();
+      assert.fail("closed a retained or unsettled account");
+    } catch (error) {
+      assert.include(error.message, code);
+    }
+  }
+
+  it("close rounds after retention", async () => {
+    // kept for ballot_retention_rounds
+    await assertCloseRejected(() => closeBallotBox(losingRound), "BallotBoxRetained");
+    await assertCloseRejected(() => closeOperatorSnapshot(losingRound, op0Pubkey), "SnapshotRetained");
+
+    await updateConfig({ ballotRetentionRounds: new anchor.BN(0) });
+    await nextEpoch();
+
+    // nobody settled it, voter states would miss the round
+    await assertCloseRejected(() => closeBallotBox(unsettledRound), "RoundNotSettled");
+    await assertCloseRejected(() => closeOperatorSnapshot(unsettledRound, op0Pubkey), "RoundNotSettled");
+
+    // the ballot box goes first, it checks the settlement against the epoch snapshot
+    await assertCloseRejected(() => closeEpochSnapshot(losingRound), "BallotBoxNotClosed");
+
+    for (const close of [
+      { account: losingRound.ballotBox, close: () => closeBallotBox(losingRound) },
+      { account: losingRound.epochSnapshot, close: () => closeEpochSnapshot(losingRound) },
+    ]) {
+      const { rent, refund } = await refundOf(close.account, close.close);
+      assert.equal(refund, rent);
+    }
+
+    // still closable once the epoch snapshot is gone
+    for (const operator of [op0Pubkey, op1Pubkey]) {
+      const { rent, refund } = await refundOf(
+        findOperatorSnapshot(losingRound.epochSnapshot, operator),
+        () => closeOperatorSnapshot(losingRound, operator),
+      );
+      assert.equal(refund, rent);
+    }
+
+    await updateConfig({ ballotRetentionRounds: new anchor.BN(32) });
+  })
+
+  // `SlotToggle.slot_removed` of a jito ticket or ncn operator state, non-zero once cooling down
+  const slotRemoved = async (pubkey: web3.PublicKey, toggleOffset: number) => {
+    const { data } = await provider.connection.getAccountInfo(pubkey);
+    return data.readBigUInt64LE(toggleOffset + 8);
+  }
+
+  it("cooldown requires the authority", async () => {
+    const other = web3.Keypair.generate()
+
+    try {
+      await dephyNcn.methods
+        .cooldownOperator()
+        .accounts({
+          config: configPubkey,
+          ncn: ncnPubkey,
+          operator: op1Pubkey,
+          authority: other.publicKey,
+        })
+        .signers([other])
+        .rpc();
+      assert.fail("cooled down operator without the authority");
+    } catch (error) {
+      assert.include(error.message, "InvalidAuthority");
+    }
+
+    try {
+      await dephyNcn.methods
+        .cooldownVaultSlasher()
+        .accounts({
+          config: configPubkey,
+          ncn: ncnPubkey,
+          vault: vaultPubkey,
+          authority: other.publicKey,
+        })
+        .signers([other])
+        .rpc();
+      assert.fail("cooled down vault slasher without the authority");
+    } catch (error) {
+      assert.include(error.message, "InvalidAuthority");
+    }
+  })
+
+  it("cooldown vault slasher and operator", async () => {
+    const slasherTx = dephyNcn.methods
+      .cooldownVaultSlasher()
+      .accounts({
+        config: configPubkey,
+        ncn: ncnPubkey,
+        vault: vaultPubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+
+    const { ncnVaultSlasherTicket } = await slasherTx.pubkeys();
+    await slasherTx.rpc();
+    // discriminator, ncn, vault, slasher, max_slashable_per_epoch, index
+    assert(await slotRemoved(ncnVaultSlasherTicket, 8 + 32 + 32 + 32 + 8 + 8) > 0n);
+
+    const operatorTx = dephyNcn.methods
+      .cooldownOperator()
+      .accounts({
+        config: configPubkey,
+        ncn: ncnPubkey,
+        operator: op1Pubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+
+    const { ncnOperatorState } = await operatorTx.pubkeys();
+    await operatorTx.rpc();
+    // discriminator, ncn, operator, index
+    assert(await slotRemoved(ncnOperatorState, 8 + 32 + 32 + 8) > 0n);
+  })
+
+  it("cooldown revokes the join request", async () => {
+    const requestTx = dephyNcn.methods
+      .requestJoin({ kind: { vault: {} } })
+      .accounts({
+        config: configPubkey,
+        target: vaultPubkey,
+        applicant: vaultAdminKeypair.publicKey,
+      })
+      .signers([vaultAdminKeypair])
+
+    const { joinRequest } = await requestTx.pubkeys()
+    await requestTx.rpc()
+
+    await dephyNcn.methods
+      .reviewJoinRequest({ approve: true })
+      .accounts({
+        config: configPubkey,
+        joinRequest,
+        authority: authority.publicKey,
+        payer: provider.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+
+    await dephyNcn.methods
+      .cooldownVault()
+      .accounts({
+        config: configPubkey,
+        ncn: ncnPubkey,
+        vault: vaultPubkey,
+        authority: authority.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+
+    const joinRequestAccount = await dephyNcn.account.joinRequest.fetch(joinRequest)
+    assert.isFalse(joinRequestAccount.approved)
+
+    try {
+      await dephyNcn.methods
+        .warmupVault()
+        .accountsPartial({
+          config: configPubkey,
+          ncn: ncnPubkey,
+          vault: vaultPubkey,
+          authority: null,
+          joinRequest,
+          joinRequestPayer: provider.publicKey,
+        })
+        .rpc()
+      assert.fail("onboarded with a revoked join request")
+    } catch (error) {
+      assert.include(error.message, "OnboardingNotAllowed")
+    }
+
+    // the authority can also reject an approved request, which closes it
+    await dephyNcn.methods
+      .reviewJoinRequest({ approve: true })
+      .accounts({
+        config: configPubkey,
+        joinRequest,
+        authority: authority.publicKey,
+        payer: provider.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+
+    await dephyNcn.methods
+      .reviewJoinRequest({ approve: false })
+      .accounts({
+        config: configPubkey,
+        joinRequest,
+        authority: authority.publicKey,
+        payer: provider.publicKey,
+      })
+      .signers([authority])
+      .rpc()
+
+    assert.isNull(await provider.connection.getAccountInfo(joinRequest))
+  })
+
+  it("switch to a voting period in slots", async () => {
+    try {
+      await updateConfig({ votingPeriod: { slots: { length: new anchor.BN(0) } } });
+      assert.fail("accepted an empty voting period");
+    } catch (error) {
+      assert.include(error.message, "InvalidConfig");
+    }
+
+    // twice the jito epoch, round numbers would go back
+    try {
+      await updateConfig({ votingPeriod: { slots: { length: new anchor.BN(64) } } });
+      assert.fail("accepted a longer voting period");
+    } catch (error) {
+      assert.include(error.message, "InvalidConfig");
+    }
+
+    const epochRound = await currentRound(provider, configPubkey);
+    await updateConfig({ votingPeriod: { slots: { length: new anchor.BN(16) } } });
+
+    const config = await dephyNcn.account.config.fetch(configPubkey);
+    assert.equal(config.votingPeriod.slots.length.toNumber(), 16);
+    assert.isAtLeast(await currentRound(provider, configPubkey), epochRound * 2);
+
+    // back to jito epochs is longer again
+    try {
+      await updateConfig({ votingPeriod: { jitoEpoch: {} } });
+      assert.fail("accepted a longer voting period");
+    } catch (error) {
+      assert.include(error.message, "InvalidConfig");
+    }
+  })
+
+  it("transfer config authority", async () => {
+    const newAuthority = web3.Keypair.generate()
+
+    await dephyNcn.methods
+      .proposeAuthority()
+      .accounts({
+        config: configPubkey,
+        authority: authority.publicKey,
+        newAuthority: newAuthority.publicKey,
+      })
+      .signers([authority])
+      .rpc();
+
+    let config = await dephyNcn.account.config.fetch(configPubkey);
+    assert(config.pendingAuthority.equals(newAuthority.publicKey));
+
+    await dephyNcn.methods
+      .acceptAuthority()
+      .accounts({
+        config: configPubkey,
+        newAuthority: newAuthority.publicKey,
+      })
+      .signers([newAuthority])
+      .rpc();
+
+    config = await dephyNcn.account.config.fetch(configPubkey);
+    assert(config.authority.equals(newAuthority.publicKey));
+    assert.isNull(config.pendingAuthority);
+  })
 });
@@ -2,7 +2,7 @@ import assert from "assert";
 import {
   Address, airdropFactory, createSolanaClient, createTransaction,
   devnet, generateKeyPairSigner, getSignatureFromTransaction, IInstruction,
-  isSolanaError, KeyPairSigner, lamports, signTransactionMessageWithSigners,
+  isSolanaError, KeyPairSigner, lamports, none, signTransactionMessageWithSigners, some,
   SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM,
   SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE,
 } from "gill";
@@ -121,6 +121,66 @@ describe("dephy-rewards with solana kit", () => {
     assert.equal(globalConfig.data.admin, admin.address)
   })
 
+  it('cannot migrate a current global config', async () => {
+    await assert.rejects(async () => {
+      await sendAndConfirmIxs([
+        await dephyRewards.getMigrateGlobalConfigInstructionAsync({
+          admin,
+          payer,
+        })
+      ], { showError: false })
+    }, (err) => {
+      assert(isSolanaError(err, SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE))
+      assert(isSolanaError(err.cause, SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM))
+      assert.equal(err.cause.context.code, dephyRewards.DEPHY_REWARDS_ERROR__ALREADY_MIGRATED)
+      return true
+    })
+  })
+
+  it('propose and accept admin', async () => {
+    const newAdmin = await generateKeyPairSigner()
+    const globalConfigPda = await dephyRewards.findGlobalConfigPda()
+
+    const tx = await sendAndConfirmIxs([
+      await dephyRewards.getProposeAdminInstructionAsync({
+        admin,
+        newAdmin: newAdmin.address,
+      })
+    ])
+
+    console.log("Propose admin transaction signature", tx)
+
+    let globalConfig = await dephyRewards.fetchGlobalConfig(rpc, globalConfigPda[0])
+    assert.equal(globalConfig.data.admin, admin.address)
+    assert.deepEqual(globalConfig.data.pendingAdmin, some(newAdmin.address))
+
+    const stranger = await generateKeyPairSigner()
+    await assert.rejects(async () => {
+      await sendAndConfirmIxs([
+        await dephyRewards.getAcceptAdminInstructionAsync({
+          newAdmin: stranger,
+        })
+      ], { showError: false })
+    }, (err) => {
+      assert(isSolanaError(err, SOLANA_ERROR__JSON_RPC__SERVER_ERROR_SEND_TRANSACTION_PREFLIGHT_FAILURE))
+      assert(isSolanaError(err.cause, SOLANA_ERROR__INSTRUCTION_ERROR__CUSTOM))
+      assert.equal(err.cause.context.code, dephyRewards.DEPHY_REWARDS_ERROR__INVALID_AUTHORITY)
+      return true
+    })
+
+    await sendAndConfirmIxs([
+      await dephyRewards.getAcceptAdminInstructionAsync({
+        newAdmin,
+      })
+    ])
+
+    globalConfig = await dephyRewards.fetchGlobalConfig(rpc, globalConfigPda[0])
+    assert.equal(globalConfig.data.admin, newAdmin.address)
+    assert.deepEqual(globalConfig.data.pendingAdmin, none())
+
+    admin = newAdmin
+  })
+
 
   it("initialize rewards state", async () => {
     const tx = await sendAndConfirmIxs([
@@ -142,12 +202,20 @@ describe("dephy-rewards with solana kit", () => {
 
   it("update merkle root", async () => {
     const merkleRoot = rewardsTree.getRoot()
+    const [merkleRootVersion] = await dephyRewards.findMerkleRootVersionPda({
+      rewardsState: rewardsStateKeypair.address,
+      version: 1,
+    })
 
     const tx = await sendAndConfirmIxs([
       dephyRewards.getUpdateMerkleRootInstruction({
         rewardsState: rewardsStateKeypair.address,
         authority: authority,
-        merkleRoot: dephyRewards.merkleRoot("Inplace", { hash: merkleRoot })
+        merkleRootVersion,
+        payer,
+        merkleRoot: dephyRewards.merkleRoot("Inplace", { hash: merkleRoot }),
+        totalAmount: userRewards.reduce((acc, { amount }) => acc + amount, 0n),
+        leafCount: userCount,
       })
     ])
 
@@ -156,6 +224,11 @@ describe("dephy-rewards with solana kit", () => {
     const rewardsState = await dephyRewards.fetchRewardsState(rpc, rewardsStateKeypair.address)
     assert(rewardsState.data.merkleRoot.__kind == "Inplace")
     assert.deepEqual(rewardsState.data.merkleRoot.hash, merkleRoot)
+    assert.equal(rewardsState.data.version, 1n)
+
+    const version = await dephyRewards.fetchMerkleRootVersion(rpc, merkleRootVersion)
+    assert.equal(version.data.version, 1n)
+    assert.equal(version.data.leafCount, userCount)
   })
 
   it("mint rewards tokens to rewards account", async () => {
@@ -196,6 +269,10 @@ describe("dephy-rewards with solana kit", () => {
       tokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
     })
     userTokenAccount = userTokenPda[0]
+    const [claimStateAddress] = await dephyRewards.findClaimStatePda({
+      rewardsState: rewardsStateKeypair.address,
+      user: user.address,
+    })
 
     const tx = await sendAndConfirmIxs([
       await splToken.getCreateAssociatedTokenIdempotentInstructionAsync({
@@ -209,22 +286,21 @@ describe("dephy-rewards with solana kit", () => {
         rewardsMint: rewardsMintKeypair.address,
         rewardsTokenAccount,
         owner: user,
+        claimState: claimStateAddress,
         beneficiaryTokenAccount: userTokenAccount,
         payer,
         rewardsTokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
-        index: userIndex,
-        totalRewards: rewardsToClaim,
-        proof,
+        args: {
+          index: userIndex,
+          totalRewards: rewardsToClaim,
+          proof,
+          vesting: null,
+        },
       })
     ])
 
     console.log("Claim rewards transaction signature", tx)
 
-    const [claimStateAddress] = await dephyRewards.findClaimStatePda({
-      rewardsState: rewardsStateKeypair.address,
-      user: user.address,
-    })
-
     // Verify the claim state
     const claimStateAccount = await dephyRewards.fetchMaybeClaimState(rpc, claimStateAddress)
     assert(claimStateAccount.exists)
@@ -240,6 +316,10 @@ describe("dephy-rewards with solana kit", () => {
     user = users[userIndex]
     const proof = rewardsTree.getProof(userIndex).proof
     const rewardsToClaim = userRewards[userIndex].amount
+    const [claimStateAddress] = await dephyRewards.findClaimStatePda({
+      rewardsState: rewardsStateKeypair.address,
+      user: user.address,
+    })
 
     await assert.rejects(async () => {
       await sendAndConfirmIxs([
@@ -248,12 +328,16 @@ describe("dephy-rewards with solana kit", () => {
           rewardsMint: rewardsMintKeypair.address,
           rewardsTokenAccount,
           owner: user,
+          claimState: claimStateAddress,
           beneficiaryTokenAccount: userTokenAccount,
           payer,
           rewardsTokenProgram: splToken.TOKEN_2022_PROGRAM_ADDRESS,
-          index: userIndex,
-          totalRewards: rewardsToClaim,
-          proof,
+          args: {
+            index: userIndex,
+            totalRewards: rewardsToClaim,
+            proof,
+            vesting: null,
+          },
         })
       ], { showError: false })
     }, (err) => {
@@ -4,6 +4,9 @@ import * as spl from "@solana/spl-token";
 import { DephyRewards } from "../target/types/dephy_rewards";
 import { assert } from "chai";
 import { buildRewardsTree } from "./rewards-tree";
+import { getClaimForMessage } from "../clients/dephy-rewards/js/src";
+import { address } from "gill";
+import { readFileSync } from "fs";
 
 
 describe("dephy-rewards", () => {
@@ -69,6 +72,86 @@ describe("dephy-rewards", () => {
     assert(rewardsState.rewardsTokenAccount.equals(rewardsTokenAccount));
   });
 
+  it("migrate only grows older rewards states", async () => {
+    try {
+      await dephyRewards.methods
+        .migrateRewardsState()
+        .accounts({
+          rewardsState: rewardsStateKeypair.publicKey,
+          authority: authority.publicKey,
+          payer: provider.wallet.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      assert.fail("migrated a current rewards state");
+    } catch (err) {
+      assert.include(err.message, "AlreadyMigrated");
+    }
+  });
+
+  it("migrate an older rewards state", async () => {
+    // fixture account, 145 bytes, with an inplace root written over an external one
+    const legacyRewardsState = new web3.PublicKey("6tP254a2YxnFh8d2S5Uxr4GhiqqppoEacWA7ozUxLwkT")
+    const legacyAuthority = web3.Keypair.fromSecretKey(Uint8Array.from(JSON.parse(
+      readFileSync("./tests/fixtures/keys/legacy-rewards-authority.json", "utf8")
+    )))
+    const migrate = (signer: web3.Keypair) => dephyRewards.methods
+      .migrateRewardsState()
+      .accounts({
+        rewardsState: legacyRewardsState,
+        authority: signer.publicKey,
+        payer: provider.wallet.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+
+    try {
+      await migrate(authority);
+      assert.fail("migrated a rewards state without its authority");
+    } catch (err) {
+      assert.include(err.message, "InvalidAuthority");
+    }
+
+    await migrate(legacyAuthority);
+
+    const accountInfo = await provider.connection.getAccountInfo(legacyRewardsState);
+    assert.equal(accountInfo.data.length, dephyRewards.account.rewardsState.size);
+    const rewardsState = await dephyRewards.account.rewardsState.fetch(legacyRewardsState);
+    assert(rewardsState.authority.equals(legacyAuthority.publicKey));
+    assert.deepEqual(rewardsState.merkleRoot.inplace.hash, Array.from({ length: 32 }, (_, i) => i + 1));
+    assert.equal(rewardsState.version.toNumber(), 0);
+    assert.equal(rewardsState.extraRewardsMints.length, 0);
+
+    try {
+      await migrate(legacyAuthority);
+      assert.fail("migrated a rewards state twice");
+    } catch (err) {
+      assert.include(err.message, "AlreadyMigrated");
+    }
+  });
+
+
+  const findMerkleRootVersion = (version: number) => web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("merkle_root_version"),
+      rewardsStateKeypair.publicKey.toBuffer(),
+      new BN(version).toArrayLike(Buffer, "le", 8)
+    ],
+    dephyRewards.programId
+  )[0];
+
+  // claim states of the primary mint have no mint seed
+  const findClaimState = (owner: web3.PublicKey, mint?: web3.PublicKey) => web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("claim_state"),
+      rewardsStateKeypair.publicKey.toBuffer(),
+      owner.toBuffer(),
+      ...(mint ? [mint.toBuffer()] : []),
+    ],
+    dephyRewards.programId
+  )[0];
+
+  const totalRewardsAmount = userRewards.reduce((acc, {amount}) => acc + amount, 0n)
 
   it("update merkle root", async () => {
     const merkleRoot = Array.from(rewardsTree.getRoot())
@@ -77,11 +160,14 @@ describe("dephy-rewards", () => {
       .updateMerkleRoot({
         merkleRoot: {
           inplace: { hash: merkleRoot }
-        }
+        },
+        totalAmount: new BN(totalRewardsAmount.toString()),
+        leafCount: userCount,
       })
-      .accounts({
+      .accountsPartial({
         rewardsState: rewardsStateKeypair.publicKey,
         authority: authority.publicKey,
+        merkleRootVersion: findMerkleRootVersion(1),
       })
       .signers([authority])
       .rpc();
@@ -90,11 +176,16 @@ describe("dephy-rewards", () => {
     
     const rewardsState = await dephyRewards.account.rewardsState.fetch(rewardsStateKeypair.publicKey);
     assert.deepEqual(Array.from(rewardsState.merkleRoot.inplace.hash), merkleRoot);
+    assert(rewardsState.version.eqn(1));
+
+    const merkleRootVersion = await dephyRewards.account.merkleRootVersion.fetch(findMerkleRootVersion(1));
+    assert(merkleRootVersion.version.eqn(1));
+    assert.deepEqual(Array.from(merkleRootVersion.merkleRoot.inplace.hash), merkleRoot);
+    assert.equal(merkleRootVersion.totalAmount.toString(), totalRewardsAmount.toString());
+    assert.equal(merkleRootVersion.leafCount, userCount);
   });
 
   it("mint rewards tokens to rewards account", async () => {
-    const totalRewardsAmount = userRewards.reduce((acc, {amount}) => acc + amount, 0n)
-
     await spl.mintTo(
       provider.connection,
       provider.wallet.payer,
@@ -115,14 +206,7 @@ describe("dephy-rewards", () => {
 
   const userIndex = 1
   const user = users[userIndex]
-  const [claimState] = web3.PublicKey.findProgramAddressSync(
-    [
-      Buffer.from("claim_state"),
-      rewardsStateKeypair.publicKey.toBuffer(),
-      user.publicKey.toBuffer()
-    ],
-    dephyRewards.programId
-  );
+  const claimState = findClaimState(user.publicKey)
 
   const proof = rewardsTree.getProof(userIndex).proof.map(b => Array.from(b))
   const rewardsToClaim = userRewards[userIndex].amount
@@ -140,15 +224,18 @@ describe("dephy-rewards", () => {
       .claimRewards({
         index: userIndex,
         totalRewards: new BN(rewardsToClaim.toString()),
-        proof
+        proof,
+        vesting: null,
       })
-      .accounts({
+      .accountsPartial({
         rewardsState: rewardsStateKeypair.publicKey,
         rewardsMint: rewardsMintKeypair.publicKey,
         rewardsTokenAccount: rewardsTokenAccount,
         owner: user.publicKey,
+        claimState,
         beneficiaryTokenAccount: userTokenAccount,
         maybeMerkleRootAccount: null,
+        merkleRootVersion: null,
         payer: provider.wallet.publicKey,
         rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
       })
@@ -177,15 +264,18 @@ describe("dephy-rewards", () => {
         .claimRewards({
           index: userIndex,
           totalRewards: new BN(rewardsToClaim.toString()),
-          proof
+          proof,
+          vesting: null,
         })
-        .accounts({
+        .accountsPartial({
           rewardsState: rewardsStateKeypair.publicKey,
           rewardsMint: rewardsMintKeypair.publicKey,
           rewardsTokenAccount: rewardsTokenAccount,
           owner: user.publicKey,
+          claimState,
           beneficiaryTokenAccount: userTokenAccount,
           maybeMerkleRootAccount: null,
+          merkleRootVersion: null,
           payer: provider.wallet.publicKey,
           rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
         })
@@ -197,4 +287,304 @@ describe("dephy-rewards", () => {
       assert.include(error.message, "AlreadyClaimed", "Expected AlreadyClaimed error");
     }
   });
+
+  it("claim against the previous root during rollover", async () => {
+    const nextRewardsTree = buildRewardsTree(userRewards.map(({ user, amount }) => ({ user, amount: amount * 2n })));
+
+    await dephyRewards.methods
+      .updateMerkleRoot({
+        merkleRoot: {
+          inplace: { hash: Array.from(nextRewardsTree.getRoot()) }
+        },
+        totalAmount: new BN((totalRewardsAmount * 2n).toString()),
+        leafCount: userCount,
+      })
+      .accountsPartial({
+        rewardsState: rewardsStateKeypair.publicKey,
+        authority: authority.publicKey,
+        merkleRootVersion: findMerkleRootVersion(2),
+      })
+      .signers([authority])
+      .rpc();
+
+    // proof of the first root
+    const rolloverIndex = 2
+    const rolloverUser = users[rolloverIndex]
+    const rolloverTokenAccount = await spl.createAssociatedTokenAccountIdempotent(
+      provider.connection,
+      provider.wallet.payer,
+      rewardsMintKeypair.publicKey,
+      rolloverUser.publicKey,
+    )
+
+    await dephyRewards.methods
+      .claimRewards({
+        index: rolloverIndex,
+        totalRewards: new BN(userRewards[rolloverIndex].amount.toString()),
+        proof: rewardsTree.getProof(rolloverIndex).proof.map(b => Array.from(b)),
+        vesting: null,
+      })
+      .accountsPartial({
+        rewardsState: rewardsStateKeypair.publicKey,
+        rewardsMint: rewardsMintKeypair.publicKey,
+        rewardsTokenAccount: rewardsTokenAccount,
+        owner: rolloverUser.publicKey,
+        claimState: findClaimState(rolloverUser.publicKey),
+        beneficiaryTokenAccount: rolloverTokenAccount,
+        maybeMerkleRootAccount: null,
+        merkleRootVersion: findMerkleRootVersion(1),
+        payer: provider.wallet.publicKey,
+        rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
+      })
+      .signers([rolloverUser])
+      .rpc();
+
+    const rolloverClaimState = findClaimState(rolloverUser.publicKey)
+    const claimStateAccount = await dephyRewards.account.claimState.fetch(rolloverClaimState);
+    assert.equal(claimStateAccount.claimedRewards.toString(), userRewards[rolloverIndex].amount.toString());
+    assert(claimStateAccount.lastClaimedVersion.eqn(1));
+  });
+
+  it("claim rewards through a relayer", async () => {
+    // holds no SOL, only signs
+    const relayedIndex = 3
+    const relayedUser = users[relayedIndex]
+    const totalRewards = userRewards[relayedIndex].amount
+    const relayedTokenAccount = await spl.createAssociatedTokenAccountIdempotent(
+      provider.connection,
+      provider.wallet.payer,
+      rewardsMintKeypair.publicKey,
+      relayedUser.publicKey,
+    )
+
+    const message = getClaimForMessage(
+      address(rewardsStateKeypair.publicKey.toBase58()),
+      totalRewards,
+      address(relayedTokenAccount.toBase58()),
+      0n,
+    )
+    const claimRewardsFor = () => dephyRewards.methods
+      .claimRewardsFor({
+        claim: {
+          index: relayedIndex,
+          totalRewards: new BN(totalRewards.toString()),
+          proof: rewardsTree.getProof(relayedIndex).proof.map(b => Array.from(b)),
+          vesting: null,
+        },
+        nonce: new BN(0),
+      })
+      .accountsPartial({
+        rewardsState: rewardsStateKeypair.publicKey,
+        rewardsMint: rewardsMintKeypair.publicKey,
+        rewardsTokenAccount: rewardsTokenAccount,
+        owner: relayedUser.publicKey,
+        claimState: findClaimState(relayedUser.publicKey),
+        beneficiaryTokenAccount: relayedTokenAccount,
+        maybeMerkleRootAccount: null,
+        merkleRootVersion: findMerkleRootVersion(1),
+        payer: provider.wallet.publicKey,
+        rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
+      })
+      .preInstructions([
+        web3.Ed25519Program.createInstructionWithPrivateKey({
+          privateKey: relayedUser.secretKey,
+          message,
+        }),
+      ])
+      .rpc();
+
+    await claimRewardsFor();
+
+    const relayedClaimState = findClaimState(relayedUser.publicKey)
+    const claimStateAccount = await dephyRewards.account.claimState.fetch(relayedClaimState);
+    assert.equal(claimStateAccount.claimedRewards.toString(), totalRewards.toString());
+    assert(claimStateAccount.nonce.eqn(1));
+
+    try {
+      await claimRewardsFor();
+      assert.fail("Should not be able to replay a signed claim");
+    } catch (error) {
+      assert.include(error.message, "InvalidNonce", "Expected InvalidNonce error");
+    }
+  });
+
+  it("claim vested rewards", async () => {
+    const now = BigInt(Math.floor(Date.now() / 1000))
+    const vestingUsers = [web3.Keypair.generate(), web3.Keypair.generate()]
+    const vestingRewards = [
+      // about half unlocked
+      { user: vestingUsers[0].publicKey, amount: 2000000n, vesting: { startTs: now - 1000n, cliffTs: now - 1000n, endTs: now + 1000n } },
+      // before the cliff
+      { user: vestingUsers[1].publicKey, amount: 2000000n, vesting: { startTs: now - 1000n, cliffTs: now + 1000n, endTs: now + 2000n } },
+    ]
+    const vestingTree = buildRewardsTree(vestingRewards)
+
+    await dephyRewards.methods
+      .updateMerkleRoot({
+        merkleRoot: {
+          inplace: { hash: Array.from(vestingTree.getRoot()) }
+        },
+        totalAmount: new BN(4000000),
+        leafCount: vestingRewards.length,
+      })
+      .accountsPartial({
+        rewardsState: rewardsStateKeypair.publicKey,
+        authority: authority.publicKey,
+        merkleRootVersion: findMerkleRootVersion(3),
+      })
+      .signers([authority])
+      .rpc();
+
+    const claimVested = async (index: number) => {
+      const { user, amount, vesting } = vestingRewards[index]
+      const tokenAccount = await spl.createAssociatedTokenAccountIdempotent(
+        provider.connection,
+        provider.wallet.payer,
+        rewardsMintKeypair.publicKey,
+        user,
+      )
+
+      await dephyRewards.methods
+        .claimRewards({
+          index,
+          totalRewards: new BN(amount.toString()),
+          proof: vestingTree.getProof(index).proof.map(b => Array.from(b)),
+          vesting: {
+            startTs: new BN(vesting.startTs.toString()),
+            cliffTs: new BN(vesting.cliffTs.toString()),
+            endTs: new BN(vesting.endTs.toString()),
+          },
+        })
+        .accountsPartial({
+          rewardsState: rewardsStateKeypair.publicKey,
+          rewardsMint: rewardsMintKeypair.publicKey,
+          rewardsTokenAccount: rewardsTokenAccount,
+          owner: user,
+          claimState: findClaimState(user),
+          beneficiaryTokenAccount: tokenAccount,
+          maybeMerkleRootAccount: null,
+          merkleRootVersion: null,
+          payer: provider.wallet.publicKey,
+          rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
+        })
+        .signers([vestingUsers[index]])
+        .rpc();
+
+      return spl.getAccount(provider.connection, tokenAccount)
+    }
+
+    const tokenAccountInfo = await claimVested(0)
+    assert(tokenAccountInfo.amount > 0n && tokenAccountInfo.amount < vestingRewards[0].amount, "Expected a partial unlock");
+
+    try {
+      await claimVested(1);
+      assert.fail("Should not be able to claim before the cliff");
+    } catch (error) {
+      assert.include(error.message, "NothingVested", "Expected NothingVested error");
+    }
+  });
+
+  it("claim rewards in an extra mint", async () => {
+    const extraMintKeypair = web3.Keypair.generate()
+    await spl.createMint(
+      provider.connection,
+      provider.wallet.payer,
+      authority.publicKey,
+      null,
+      6,
+      extraMintKeypair,
+    );
+    const extraRewardsTokenAccount = spl.getAssociatedTokenAddressSync(
+      extraMintKeypair.publicKey,
+      rewardsVault,
+      true
+    )
+
+    await dephyRewards.methods
+      .addRewardsMint()
+      .accountsPartial({
+        rewardsState: rewardsStateKeypair.publicKey,
+        authority: authority.publicKey,
+        rewardsMint: extraMintKeypair.publicKey,
+        rewardsTokenAccount: extraRewardsTokenAccount,
+        payer: provider.wallet.publicKey,
+        rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
+      })
+      .signers([authority])
+      .rpc();
+
+    const rewardsState = await dephyRewards.account.rewardsState.fetch(rewardsStateKeypair.publicKey);
+    assert(rewardsState.extraRewardsMints[0].equals(extraMintKeypair.publicKey));
+
+    const extraUser = web3.Keypair.generate()
+    const extraRewards = [
+      { user: extraUser.publicKey, amount: 1000000n },
+      { user: extraUser.publicKey, mint: extraMintKeypair.publicKey, amount: 3000000n },
+    ]
+    const extraTree = buildRewardsTree(extraRewards)
+
+    await dephyRewards.methods
+      .updateMerkleRoot({
+        merkleRoot: {
+          inplace: { hash: Array.from(extraTree.getRoot()) }
+        },
+        totalAmount: new BN(4000000),
+        leafCount: extraRewards.length,
+      })
+      .accountsPartial({
+        rewardsState: rewardsStateKeypair.publicKey,
+        authority: authority.publicKey,
+        merkleRootVersion: findMerkleRootVersion(4),
+      })
+      .signers([authority])
+      .rpc();
+
+    await spl.mintTo(
+      provider.connection,
+      provider.wallet.payer,
+      extraMintKeypair.publicKey,
+      extraRewardsTokenAccount,
+      authority,
+      3000000
+    );
+
+    const tokenAccount = await spl.createAssociatedTokenAccountIdempotent(
+      provider.connection,
+      provider.wallet.payer,
+      extraMintKeypair.publicKey,
+      extraUser.publicKey,
+    )
+
+    await dephyRewards.methods
+      .claimRewards({
+        index: 1,
+        totalRewards: new BN(extraRewards[1].amount.toString()),
+        proof: extraTree.getProof(1).proof.map(b => Array.from(b)),
+        vesting: null,
+      })
+      .accountsPartial({
+        rewardsState: rewardsStateKeypair.publicKey,
+        rewardsMint: extraMintKeypair.publicKey,
+        rewardsTokenAccount: extraRewardsTokenAccount,
+        owner: extraUser.publicKey,
+        claimState: findClaimState(extraUser.publicKey, extraMintKeypair.publicKey),
+        beneficiaryTokenAccount: tokenAccount,
+        maybeMerkleRootAccount: null,
+        merkleRootVersion: null,
+        payer: provider.wallet.publicKey,
+        rewardsTokenProgram: spl.TOKEN_PROGRAM_ID,
+      })
+      .signers([extraUser])
+      .rpc();
+
+    const tokenAccountInfo = await spl.getAccount(provider.connection, tokenAccount);
+    assert.equal(tokenAccountInfo.amount, extraRewards[1].amount);
+
+    // tracked apart from the primary mint claims of the same owner
+    const claimStateAccount = await dephyRewards.account.claimState.fetch(
+      findClaimState(extraUser.publicKey, extraMintKeypair.publicKey)
+    );
+    assert(claimStateAccount.mint.equals(extraMintKeypair.publicKey));
+    assert.isNull(await provider.connection.getAccountInfo(findClaimState(extraUser.publicKey)));
+  });
 });
@@ -3,13 +3,35 @@ import { MerkleTree, hash } from "./merkle-tree";
 
 export type RewardsNode = {
   user: PublicKey;
+  // unset for the primary rewards mint of the rewards state
+  mint?: PublicKey;
   amount: bigint;
+  vesting?: VestingSchedule;
+}
+
+// unix timestamps, nothing unlocks before the cliff, then linearly from start to end
+export type VestingSchedule = {
+  startTs: bigint;
+  cliffTs: bigint;
+  endTs: bigint;
 }
 
 export function hashNode(node: RewardsNode): Buffer {
   const amountBuffer = Buffer.alloc(8);
   amountBuffer.writeBigUInt64LE(node.amount, 0);
-  return hash(node.user.toBuffer(), amountBuffer);
+  // same as `dephy_rewards::hash_leaf`
+  const data = [amountBuffer];
+  if (node.mint) {
+    data.unshift(node.mint.toBuffer());
+  }
+  if (node.vesting) {
+    const vestingBuffer = Buffer.alloc(24);
+    vestingBuffer.writeBigInt64LE(node.vesting.startTs, 0);
+    vestingBuffer.writeBigInt64LE(node.vesting.cliffTs, 8);
+    vestingBuffer.writeBigInt64LE(node.vesting.endTs, 16);
+    data.push(vestingBuffer);
+  }
+  return hash(node.user.toBuffer(), Buffer.concat(data));
 }
 
 export function buildRewardsTree(nodes: RewardsNode[]) {